@@ -1,13 +1,27 @@
 use if_chain::if_chain;
 use proc_macro::TokenStream;
-use quote::{quote, format_ident};
+use quote::{quote, quote_spanned, format_ident};
 use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     Item::{self, Struct},
-    ItemStruct, Fields, FieldsNamed, Field,
+    ItemStruct, Fields, FieldsNamed, Field, Ident, LitInt, LitStr, Path, Token,
+    Expr, ExprLit, Lit, Meta, MetaNameValue, Type, TypeArray, Visibility,
+    ext::IdentExt,
+    spanned::Spanned,
 };
 
-pub fn bitfield_impl(input: TokenStream) -> syn::Result<TokenStream> {
-    let item: Item = syn::parse(input.clone())?;
+pub fn bitfield_impl(args: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let BitfieldOptions { bytes, derives, msb_first, ctor, windows } = syn::parse(args)?;
+    let item: Item = syn::parse(input)?;
+
+    let error_span = match &item {
+        Struct(item_struct) => item_struct.ident.span(),
+        Item::Enum(item_enum) => item_enum.ident.span(),
+        Item::Union(item_union) => item_union.ident.span(),
+        _ => proc_macro2::Span::call_site(),
+    };
 
     if_chain! {
         if let Struct(item_struct) = item;
@@ -15,14 +29,193 @@ pub fn bitfield_impl(input: TokenStream) -> syn::Result<TokenStream> {
         if let Fields::Named(fields) = fields;
         if let FieldsNamed { named: fields, .. } = fields;
         then {
+            // Fields marked `#[skip]` are stored as they are, next to the packed data, and take no
+            // part in the bit layout
+            let (skipped_fields, fields) = partition_skipped_fields(fields)?;
+
+            if fields.is_empty() {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("#[bitfield] struct `{}` must have at least one field", ident),
+                ));
+            }
+
+            // Every field gets a `get_*_checked` getter, which a field named `*_checked` would
+            // otherwise define a second time as its plain getter
+            for field in &fields {
+                if let Some(ident) = &field.ident {
+                    let checked_name = format!("{}_checked", ident.unraw());
+                    let clashing_field = fields
+                        .iter()
+                        .filter_map(|other_field| other_field.ident.as_ref())
+                        .find(|other_ident| other_ident.unraw() == checked_name);
+                    if let Some(clashing_field) = clashing_field {
+                        return Err(syn::Error::new(
+                            clashing_field.span(),
+                            format!(
+                                "the getter of field `{}` has the same name as the checked getter of field `{}`: `get_{}`",
+                                clashing_field, ident, checked_name,
+                            ),
+                        ));
+                    }
+                }
+            }
+
             let bit_widths: proc_macro2::TokenStream = fields.iter().map(|field| {
                 let Field { ty, .. } = field;
                 quote! { + <#ty as ::bitfield::Specifier>::BITS }
             }).collect();
 
+            // An explicit byte count fixes the array length up front, and is checked against the
+            // sum of the field widths once those are known to the compiler.
+            let (byte_count, byte_count_check) = match &bytes {
+                Some(bytes) => {
+                    let bytes = bytes.base10_parse::<usize>()?;
+                    let message = format!("the fields of `{}` do not add up to `bytes = {}` ({} bits)", ident, bytes, bytes * 8);
+                    (
+                        quote!(#bytes),
+                        quote! {
+                            const _: () = assert!((0 #bit_widths) == #bytes * 8, #message);
+                        },
+                    )
+                },
+                None => (quote!((0 #bit_widths) / 8), quote!()),
+            };
+
+            // A `#[bits = N]` attribute documents the width of a field. Comparing array lengths rather
+            // than asserting on the values lets the compiler's error show the actual width.
+            let bits_checks = fields
+                .iter()
+                .map(|field| {
+                    let ty = &field.ty;
+                    let bits_attributes = field.attrs.iter().filter(|attr| attr.path().is_ident("bits"));
+
+                    bits_attributes.map(|attr| {
+                        if_chain! {
+                            if let Meta::NameValue(MetaNameValue { value, .. }) = &attr.meta;
+                            if let Expr::Lit(ExprLit { lit: Lit::Int(bits), .. }) = value;
+                            then {
+                                bits.base10_parse::<usize>()?;
+                                Ok(quote_spanned! {bits.span()=>
+                                    const _: [(); #bits] = [(); <#ty as ::bitfield::Specifier>::BITS];
+                                })
+                            } else {
+                                Err(syn::Error::new_spanned(attr, "expected `bits = N`"))
+                            }
+                        }
+                    }).collect::<syn::Result<proc_macro2::TokenStream>>()
+                })
+                .collect::<syn::Result<proc_macro2::TokenStream>>()?;
+
+            // `Debug` is implemented by hand so that it shows the field values rather than the raw
+            // bytes, and `PartialEq` and `Eq` so that they compare the storage bytes, which are
+            // equal exactly when all the fields are. Every other requested trait is forwarded to a
+            // regular derive.
+            let is_derive = |path: &Path, name: &str| path.segments.last().is_some_and(|segment| segment.ident == name);
+            let (debug_derives, forwarded_derives): (Vec<Path>, Vec<Path>) = derives
+                .into_iter()
+                .partition(|path| is_derive(path, "Debug"));
+            let (partial_eq_derives, forwarded_derives): (Vec<Path>, Vec<Path>) = forwarded_derives
+                .into_iter()
+                .partition(|path| is_derive(path, "PartialEq"));
+            let (eq_derives, forwarded_derives): (Vec<Path>, Vec<Path>) = forwarded_derives
+                .into_iter()
+                .partition(|path| is_derive(path, "Eq"));
+
+            let skipped_field_idents: Vec<&Ident> = skipped_fields.iter().filter_map(|field| field.ident.as_ref()).collect();
+
+            let derive_attribute = if forwarded_derives.is_empty() {
+                quote!()
+            } else {
+                quote!(#[derive(#(#forwarded_derives),*)])
+            };
+
+            let partial_eq_impl = if partial_eq_derives.is_empty() {
+                quote!()
+            } else {
+                quote! {
+                    impl ::core::cmp::PartialEq for #ident {
+                        fn eq(&self, other: &Self) -> bool {
+                            self.data == other.data #(&& self.#skipped_field_idents == other.#skipped_field_idents)*
+                        }
+                    }
+                }
+            };
+
+            let eq_impl = if eq_derives.is_empty() {
+                quote!()
+            } else {
+                quote! {
+                    impl ::core::cmp::Eq for #ident {}
+                }
+            };
+
+            let debug_impl = if debug_derives.is_empty() {
+                quote!()
+            } else {
+                let bit_field_values = fields.iter().filter_map(|field| {
+                    field.ident.as_ref().map(|ident| {
+                        let getter_name = format_ident!("get_{}", ident);
+                        let field_name = ident.to_string();
+                        match &field.ty {
+                            Type::Array(TypeArray { len, .. }) => quote! {
+                                .field(#field_name, &(0..#len).map(|index| self.#getter_name(index)).collect::<::std::vec::Vec<_>>())
+                            },
+                            _ => quote!(.field(#field_name, &self.#getter_name())),
+                        }
+                    })
+                });
+                let skipped_field_values = skipped_field_idents.iter().map(|ident| {
+                    let field_name = ident.to_string();
+                    quote!(.field(#field_name, &self.#ident))
+                });
+                let struct_name = ident.to_string();
+
+                quote! {
+                    impl std::fmt::Debug for #ident {
+                        fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                            fmt.debug_struct(#struct_name)
+                                #(#bit_field_values)*
+                                #(#skipped_field_values)*
+                                .finish()
+                        }
+                    }
+                }
+            };
+
+            let bit_order = if msb_first {
+                quote!(::bitfield::field_data::BitOrder::MsbFirst)
+            } else {
+                quote!(::bitfield::field_data::BitOrder::LsbFirst)
+            };
+
+            let big_endian_fields = fields.iter().map(field_is_big_endian).collect::<syn::Result<Vec<_>>>()?;
+
+            // Only whole bytes can be reversed
+            let endian_checks: proc_macro2::TokenStream = fields
+                .iter()
+                .zip(&big_endian_fields)
+                .filter(|(_, big_endian)| **big_endian)
+                .filter_map(|(field, _)| {
+                    field.ident.as_ref().map(|ident| {
+                        let specifier_ty = specifier_type(&field.ty);
+                        let message = format!("`#[endian = \"big\"]` requires field `{}` to be a whole number of bytes", ident);
+                        quote_spanned! {ident.span()=>
+                            const _: () = assert!(<#specifier_ty as ::bitfield::Specifier>::BITS % 8 == 0, #message);
+                        }
+                    })
+                })
+                .collect();
+
             let accessors: proc_macro2::TokenStream = fields.iter().enumerate().map(|(field_index, field)| {
-                let Field { ident, ty, .. } = field;
+                let Field { vis: field_vis, ident, ty, .. } = field;
                 if let Some(ident) = ident {
+                    // Accessors share the struct's visibility unless the field declares its own
+                    let accessor_vis = match field_vis {
+                        Visibility::Inherited => &vis,
+                        _ => field_vis,
+                    };
+
                     let previous_bit_widths: proc_macro2::TokenStream = fields
                         .iter()
                         .enumerate()
@@ -34,31 +227,89 @@ pub fn bitfield_impl(input: TokenStream) -> syn::Result<TokenStream> {
                             quote! { + <#ty as ::bitfield::Specifier>::BITS }
                         }).collect();
 
-                    let current_field_bit_count = quote!(<#ty as ::bitfield::Specifier>::BITS);
-                    let current_field_accessor_type_name = quote!(<#ty as ::bitfield::Specifier>::ACCESSOR);
+                    // The position of each field is a constant, usable in const contexts
+                    let const_prefix = ident.unraw().to_string().to_uppercase();
+                    let offset_name = format_ident!("{}_OFFSET", const_prefix);
+                    let width_name = format_ident!("{}_WIDTH", const_prefix);
+
+                    // The accessors of an array field read and write one element at a time
+                    let (index_parameter, index_argument, index_check, current_field_bit_start_index, specifier_ty) = match ty {
+                        Type::Array(TypeArray { elem, len, .. }) => {
+                            let field_name = ident.to_string();
+                            (
+                                quote!(index: usize,),
+                                quote!(index,),
+                                quote! {
+                                    assert!(index < #len, "index {} is out of range for field `{}`", index, #field_name);
+                                },
+                                quote!(Self::#offset_name + index * <#elem as ::bitfield::Specifier>::BITS),
+                                specifier_type(ty),
+                            )
+                        },
+                        _ => (quote!(), quote!(), quote!(), quote!(Self::#offset_name), specifier_type(ty)),
+                    };
+
+                    let current_field_bit_count = quote!(<#specifier_ty as ::bitfield::Specifier>::BITS);
+                    let current_field_accessor_type_name = quote!(<#specifier_ty as ::bitfield::Specifier>::ACCESSOR);
+
+                    // Field data is little-endian, so a big-endian field has its bytes reversed on the
+                    // way in and out
+                    let byte_order_conversion = if big_endian_fields[field_index] {
+                        quote! {
+                            let field_data = ::bitfield::field_data::reverse_field_bytes::<SIZE>(field_data, BIT_COUNT);
+                        }
+                    } else {
+                        quote!()
+                    };
 
                     let getter_name = format_ident!("get_{}", ident);
+                    let checked_getter_name = format_ident!("get_{}_checked", ident);
                     let setter_name = format_ident!("set_{}", ident);
+                    let with_name = format_ident!("with_{}", ident);
 
                     quote! {
-                        fn #getter_name(&self) -> #current_field_accessor_type_name {
-                            let current_field_bit_start_index = 0 #previous_bit_widths;
-                            let current_field_bit_count = #current_field_bit_count;
+                        #accessor_vis const #offset_name: usize = 0 #previous_bit_widths;
+                        #accessor_vis const #width_name: usize = <#ty as ::bitfield::Specifier>::BITS;
+
+                        #accessor_vis fn #getter_name(&self, #index_parameter) -> #current_field_accessor_type_name {
+                            #index_check
+                            let current_field_bit_start_index = #current_field_bit_start_index;
 
                             const SIZE: usize = std::mem::size_of::<#current_field_accessor_type_name>();
+                            const BIT_COUNT: usize = #current_field_bit_count;
 
-                            let field_data = ::bitfield::field_data::get_field_data::<SIZE>(&self.data, current_field_bit_start_index, current_field_bit_count);
-                            <#current_field_accessor_type_name as ::bitfield::Serialize<SIZE>>::deserialize(field_data)
+                            let field_data = ::bitfield::field_data::get_field_data_sized::<SIZE, BIT_COUNT>(&self.data, current_field_bit_start_index, #bit_order);
+                            #byte_order_conversion
+                            <#current_field_accessor_type_name as ::bitfield::Serialize<SIZE>>::deserialize_field(field_data, BIT_COUNT)
                         }
 
-                        fn #setter_name(&mut self, val: #current_field_accessor_type_name) {
-                            let current_field_bit_start_index = 0 #previous_bit_widths;
-                            let current_field_bit_count = #current_field_bit_count;
+                        #accessor_vis fn #checked_getter_name(&self, #index_parameter) -> ::core::result::Result<#current_field_accessor_type_name, ::bitfield::InvalidDiscriminant> {
+                            #index_check
+                            let current_field_bit_start_index = #current_field_bit_start_index;
 
                             const SIZE: usize = std::mem::size_of::<#current_field_accessor_type_name>();
+                            const BIT_COUNT: usize = #current_field_bit_count;
+
+                            let field_data = ::bitfield::field_data::get_field_data_sized::<SIZE, BIT_COUNT>(&self.data, current_field_bit_start_index, #bit_order);
+                            #byte_order_conversion
+                            <#current_field_accessor_type_name as ::bitfield::Serialize<SIZE>>::try_deserialize_field(field_data, BIT_COUNT)
+                        }
+
+                        #accessor_vis fn #setter_name(&mut self, #index_parameter val: #current_field_accessor_type_name) {
+                            #index_check
+                            let current_field_bit_start_index = #current_field_bit_start_index;
+
+                            const SIZE: usize = std::mem::size_of::<#current_field_accessor_type_name>();
+                            const BIT_COUNT: usize = #current_field_bit_count;
 
                             let field_data = <#current_field_accessor_type_name as ::bitfield::Serialize<SIZE>>::serialize(val);
-                            ::bitfield::field_data::set_field_data::<SIZE>(&mut self.data, field_data, current_field_bit_start_index, current_field_bit_count);
+                            #byte_order_conversion
+                            ::bitfield::field_data::set_field_data_sized::<SIZE, BIT_COUNT>(&mut self.data, field_data, current_field_bit_start_index, #bit_order);
+                        }
+
+                        #accessor_vis fn #with_name(mut self, #index_parameter val: #current_field_accessor_type_name) -> Self {
+                            self.#setter_name(#index_argument val);
+                            self
                         }
                     }
                 } else {
@@ -66,29 +317,331 @@ pub fn bitfield_impl(input: TokenStream) -> syn::Result<TokenStream> {
                 }
             }).collect();
 
+            // `new_with` takes the value of every field, in declaration order, and sets each one on a
+            // zeroed bitfield. The parameters are named after the fields, so the locals have
+            // mixed-site spans to keep them apart from any field with the same name.
+            let constructor = if ctor {
+                let bitfield_local = Ident::new("bitfield", proc_macro2::Span::mixed_site());
+                let index_local = Ident::new("index", proc_macro2::Span::mixed_site());
+                let val_local = Ident::new("val", proc_macro2::Span::mixed_site());
+                let parameters = fields.iter().filter_map(|field| {
+                    let ty = &field.ty;
+                    field.ident.as_ref().map(|ident| quote!(#ident: <#ty as ::bitfield::Specifier>::ACCESSOR))
+                });
+                let assignments = fields.iter().filter_map(|field| {
+                    field.ident.as_ref().map(|ident| {
+                        let setter_name = format_ident!("set_{}", ident);
+                        match &field.ty {
+                            Type::Array(TypeArray { .. }) => quote! {
+                                for (#index_local, #val_local) in #ident.into_iter().enumerate() {
+                                    #bitfield_local.#setter_name(#index_local, #val_local);
+                                }
+                            },
+                            _ => quote!(#bitfield_local.#setter_name(#ident);),
+                        }
+                    })
+                });
+
+                quote! {
+                    #vis fn new_with(#(#parameters),*) -> Self {
+                        let mut #bitfield_local = Self::new();
+                        #(#assignments)*
+                        #bitfield_local
+                    }
+                }
+            } else {
+                quote!()
+            };
+
+            // A window covers the bits from the start of its first field to the end of its last one,
+            // with the first field in the least significant bits. The bits are read as they are
+            // stored, so a big-endian field inside a window is not reversed.
+            let field_position = |name: &Ident| {
+                fields
+                    .iter()
+                    .position(|field| field.ident.as_ref() == Some(name))
+                    .ok_or_else(|| syn::Error::new(name.span(), format!("no field named `{}` in `{}`", name, ident)))
+            };
+            let window_accessors = windows.iter().map(|Window { name, from, to }| {
+                if field_position(to)? < field_position(from)? {
+                    return Err(syn::Error::new(to.span(), format!("field `{}` comes before field `{}`", to, from)));
+                }
+
+                let const_name = |field: &Ident, suffix| format_ident!("{}_{}", field.unraw().to_string().to_uppercase(), suffix);
+                let from_offset = const_name(from, "OFFSET");
+                let to_offset = const_name(to, "OFFSET");
+                let to_width = const_name(to, "WIDTH");
+                let getter_name = format_ident!("get_{}", name);
+                let setter_name = format_ident!("set_{}", name);
+                let message = format!("window `{}` is wider than the 64 bits of its `u64` accessor", name);
+
+                Ok(quote! {
+                    #vis fn #getter_name(&self) -> u64 {
+                        const SIZE: usize = 8;
+                        const BIT_COUNT: usize = #ident::#to_offset + #ident::#to_width - #ident::#from_offset;
+                        const { assert!(BIT_COUNT <= 64, #message) };
+
+                        let field_data = ::bitfield::field_data::get_field_data_sized::<SIZE, BIT_COUNT>(&self.data, Self::#from_offset, #bit_order);
+                        <u64 as ::bitfield::Serialize<SIZE>>::deserialize_field(field_data, BIT_COUNT)
+                    }
+
+                    #vis fn #setter_name(&mut self, val: u64) {
+                        const SIZE: usize = 8;
+                        const BIT_COUNT: usize = #ident::#to_offset + #ident::#to_width - #ident::#from_offset;
+                        const { assert!(BIT_COUNT <= 64, #message) };
+
+                        let field_data = <u64 as ::bitfield::Serialize<SIZE>>::serialize(val);
+                        ::bitfield::field_data::set_field_data_sized::<SIZE, BIT_COUNT>(&mut self.data, field_data, Self::#from_offset, #bit_order);
+                    }
+                })
+            }).collect::<syn::Result<proc_macro2::TokenStream>>()?;
+
+            // A bitfield is itself a specifier, so that it can be nested inside another bitfield.
+            // One with `#[skip]` fields isn't, since those fields are neither part of its bytes nor
+            // counted in its size.
+            let specifier_impls = if skipped_fields.is_empty() {
+                quote! {
+                    impl ::bitfield::Specifier for #ident {
+                        const BITS: usize = 0 #bit_widths;
+                        type ACCESSOR = #ident;
+                    }
+
+                    impl ::bitfield::Serialize<{ #byte_count }> for #ident {
+                        type Type = #ident;
+
+                        fn serialize(t: #ident) -> [u8; #byte_count] {
+                            t.data
+                        }
+
+                        fn deserialize(bytes: [u8; #byte_count]) -> #ident {
+                            Self { data: bytes }
+                        }
+                    }
+                }
+            } else {
+                quote!()
+            };
+
             Ok(quote! {
                 #(#attrs)*
+                #derive_attribute
                 #vis #struct_token #ident #generics {
-                    data: [u8; (0 #bit_widths) / 8]
+                    data: [u8; #byte_count],
+                    #(#skipped_fields),*
                 }
                 #semi_token
 
+                // The marker type for the total size modulo 8 only implements the trait for a
+                // remainder of 0. Naming it in a bound of a top-level item means the check runs
+                // whether or not anything in the struct is ever used.
+                const _: fn() = || {
+                    fn total_size_is_multiple_of_eight_bits<T: ::bitfield::checks::TotalSizeIsMultipleOfEightBits>() {}
+                    total_size_is_multiple_of_eight_bits::<
+                        <::bitfield::checks::Mod8<{ (0 #bit_widths) % 8 }> as ::bitfield::checks::Mod8Check>::Type
+                    >();
+                };
+
+                #byte_count_check
+                #bits_checks
+                #endian_checks
+
                 impl #ident {
-                    fn new() -> Self {
-                        Self { data: [0; (0 #bit_widths) / 8] }
+                    #vis const BYTES: usize = #byte_count;
+                    #vis const BITS: usize = 0 #bit_widths;
+
+                    #vis fn new() -> Self {
+                        Self { data: [0; #byte_count], #(#skipped_field_idents: ::core::default::Default::default()),* }
                     }
 
-                    fn checks() -> impl ::bitfield::checks::TotalSizeIsMultipleOfEightBits {
-                        const mod8: usize = (0 #bit_widths) % 8;
-                        type ReturnType = <::bitfield::checks::Mod8::<mod8> as ::bitfield::checks::Mod8Check>::Type;
-                        ReturnType {}
+                    #constructor
+
+                    #vis fn from_bytes(bytes: [u8; #byte_count]) -> Self {
+                        Self { data: bytes, #(#skipped_field_idents: ::core::default::Default::default()),* }
+                    }
+
+                    #vis fn as_bytes(&self) -> &[u8; #byte_count] {
+                        &self.data
+                    }
+
+                    #vis fn into_bytes(self) -> [u8; #byte_count] {
+                        self.data
                     }
 
                     #accessors
+                    #window_accessors
+                }
+
+                impl ::core::default::Default for #ident {
+                    fn default() -> Self {
+                        Self::new()
+                    }
                 }
+
+                #specifier_impls
+
+                #debug_impl
+                #partial_eq_impl
+                #eq_impl
             }.into())
         } else {
-            Ok(input)
+            Err(syn::Error::new(error_span, "#[bitfield] can only be applied to structs with named fields"))
+        }
+    }
+}
+
+// The specifier that each accessor of a field reads and writes, which for an array field is the
+// element type
+fn specifier_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Array(TypeArray { elem, .. }) => elem,
+        _ => ty,
+    }
+}
+
+// Splits the fields marked `#[skip]`, with that attribute removed, from the fields that are packed
+// into the data array
+fn partition_skipped_fields(fields: impl IntoIterator<Item = Field>) -> syn::Result<(Vec<Field>, Vec<Field>)> {
+    let mut skipped_fields = Vec::new();
+    let mut bit_fields = Vec::new();
+
+    for mut field in fields {
+        let mut skipped = false;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("skip")) {
+            if !matches!(attr.meta, Meta::Path(_)) {
+                return Err(syn::Error::new(attr.span(), "expected `#[skip]`"));
+            }
+            skipped = true;
         }
+
+        if skipped {
+            field.attrs.retain(|attr| !attr.path().is_ident("skip"));
+            skipped_fields.push(field);
+        } else {
+            bit_fields.push(field);
+        }
+    }
+
+    Ok((skipped_fields, bit_fields))
+}
+
+// Reads the optional `#[endian = "big"]` or `#[endian = "little"]` attribute of a field
+fn field_is_big_endian(field: &Field) -> syn::Result<bool> {
+    let mut big_endian = false;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("endian")) {
+        big_endian = if_chain! {
+            if let Meta::NameValue(MetaNameValue { value, .. }) = &attr.meta;
+            if let Expr::Lit(ExprLit { lit: Lit::Str(endian), .. }) = value;
+            if endian.value() == "big" || endian.value() == "little";
+            then {
+                endian.value() == "big"
+            } else {
+                return Err(syn::Error::new(attr.span(), "expected `endian = \"big\"` or `endian = \"little\"`"));
+            }
+        };
+    }
+
+    Ok(big_endian)
+}
+
+#[derive(Default)]
+struct BitfieldOptions {
+    bytes: Option<LitInt>,
+    derives: Vec<Path>,
+    msb_first: bool,
+    ctor: bool,
+    windows: Vec<Window>,
+}
+
+// A `window(name = "...", from = first_field, to = last_field)` option, which reads and writes
+// the bits of a run of adjacent fields as a single integer
+struct Window {
+    name: Ident,
+    from: Ident,
+    to: Ident,
+}
+
+impl Parse for Window {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+        let mut from = None;
+        let mut to = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+
+            if key == "name" {
+                let lit: LitStr = input.parse()?;
+                name = Some(lit.parse::<Ident>()?);
+            } else if key == "from" {
+                from = Some(input.parse()?);
+            } else if key == "to" {
+                to = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'name', 'from' or 'to'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        match (name, from, to) {
+            (Some(name), Some(from), Some(to)) => Ok(Self { name, from, to }),
+            _ => Err(input.error("expected `window(name = \"...\", from = first_field, to = last_field)`")),
+        }
+    }
+}
+
+impl Parse for BitfieldOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+
+            if key == "bytes" {
+                if options.bytes.is_some() {
+                    return Err(syn::Error::new(key.span(), "duplicate 'bytes' option"));
+                }
+
+                let _: Token![=] = input.parse()?;
+                let bytes: LitInt = input.parse()?;
+                bytes.base10_parse::<usize>()?;
+                options.bytes = Some(bytes);
+            } else if key == "derive" {
+                let content;
+                parenthesized!(content in input);
+                options.derives.extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+            } else if key == "bit_order" {
+                let _: Token![=] = input.parse()?;
+                let bit_order: LitStr = input.parse()?;
+                options.msb_first = match bit_order.value().as_str() {
+                    "lsb" => false,
+                    "msb" => true,
+                    _ => return Err(syn::Error::new(bit_order.span(), "expected `bit_order = \"lsb\"` or `bit_order = \"msb\"`")),
+                };
+            } else if key == "ctor" {
+                options.ctor = true;
+            } else if key == "window" {
+                let content;
+                parenthesized!(content in input);
+                options.windows.push(content.parse()?);
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'bytes', 'derive', 'bit_order', 'ctor' or 'window'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(options)
     }
 }
\ No newline at end of file
@@ -1,33 +1,75 @@
-use if_chain::if_chain;
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::Literal;
+use quote::{format_ident, quote};
 use std::collections::HashMap;
 use syn::{
+    Attribute,
     Data,
     DeriveInput,
     Expr,
+    ExprLit,
+    ExprUnary,
     Ident,
     Lit,
+    LitInt,
+    Token,
+    UnOp,
     Variant,
     punctuated::Punctuated,
     token::Comma,
 };
 
 pub fn bitfield_specifier_derive_impl(input: TokenStream) -> syn::Result<TokenStream> {
-    let DeriveInput { ident: enum_name, data, .. }: DeriveInput = syn::parse(input)?;
+    let DeriveInput { attrs, ident: enum_name, data, .. }: DeriveInput = syn::parse(input)?;
 
     if let Data::Enum(data) = data {
+        if data.variants.is_empty() {
+            return Err(syn::Error::new(
+                enum_name.span(),
+                format!("BitfieldSpecifier cannot be derived for `{}` because it has no variants", enum_name),
+            ));
+        }
+
+        // The workshop's rule for specifier enums. The width still comes from the largest
+        // discriminant (or `bits = N`), so this alone doesn't make every bit pattern of that width
+        // a variant: `A = 0, B = 5` takes 3 bits. Reading a value that matches no variant panics
+        // in the getter and is an `InvalidDiscriminant` error in the checked getter.
+        let variant_count = data.variants.len();
+        if !variant_count.is_power_of_two() {
+            return Err(syn::Error::new(
+                enum_name.span(),
+                format!("BitfieldSpecifier expected a number of variants which is a power of 2, but `{}` has {}", enum_name, variant_count),
+            ));
+        }
+
         let variants = enum_variants(data.variants)?;
 
-        let maximum_discriminant: &u32 = variants
+        let maximum_discriminant: &u64 = variants
             .iter()
             .max_by(|a, b| { a.1.cmp(b.1) })
             .unwrap()
             .1;
 
-        let size_bits = (std::mem::size_of::<u32>() * 8) - (maximum_discriminant.leading_zeros() as usize);
+        let minimum_size_bits = (std::mem::size_of::<u64>() * 8) - (maximum_discriminant.leading_zeros() as usize);
+
+        let size_bits = match specifier_bits_from_attributes(&attrs)? {
+            Some(bits) => {
+                let size_bits = bits.base10_parse::<usize>()?;
+                if size_bits < minimum_size_bits {
+                    return Err(syn::Error::new(
+                        bits.span(),
+                        format!("`bits = {}` is too narrow for `{}`, whose discriminants need {} bits", size_bits, enum_name, minimum_size_bits),
+                    ));
+                }
+                size_bits
+            },
+            None => minimum_size_bits,
+        };
 
-        let size_bytes = (size_bits + 7) / 8;
+        // Getters and setters serialize a field to as many bytes as its accessor takes up in memory,
+        // which for an enum is however many bytes Rust uses for its discriminant.
+        let size_bytes = quote!(std::mem::size_of::<#enum_name>());
+        let size_check_message = format!("`{}` is too small in memory to hold {} bits; give it a wider `#[repr]`", enum_name, size_bits);
 
         let deserialize_match_arms: Vec<_> = variants
             .iter()
@@ -38,6 +80,34 @@ pub fn bitfield_specifier_derive_impl(input: TokenStream) -> syn::Result<TokenSt
             })
             .collect();
 
+        let try_deserialize_match_arms: Vec<_> = variants
+            .iter()
+            .map(|(ident, value)| {
+                quote! {
+                    #value => ::core::result::Result::Ok(#enum_name::#ident),
+                }
+            })
+            .collect();
+
+        // The enum converts to and from the narrowest unsigned integer that holds the specifier's
+        // bits. Discriminants are at most a `u64`, which is as wide as it gets.
+        let integer_type = match size_bits {
+            0..=8 => format_ident!("u8"),
+            9..=16 => format_ident!("u16"),
+            17..=32 => format_ident!("u32"),
+            _ => format_ident!("u64"),
+        };
+
+        let try_from_match_arms: Vec<_> = variants
+            .iter()
+            .map(|(ident, value)| {
+                let value = Literal::u64_unsuffixed(*value);
+                quote! {
+                    #value => ::core::result::Result::Ok(#enum_name::#ident),
+                }
+            })
+            .collect();
+
         let panic_string = format!("unexpected value for `{}`: {{}}", enum_name);
 
         Ok(quote! {
@@ -46,19 +116,51 @@ pub fn bitfield_specifier_derive_impl(input: TokenStream) -> syn::Result<TokenSt
                 type ACCESSOR = #enum_name;
             }
 
-            impl ::bitfield::Serialize<#size_bytes> for #enum_name {
+            const _: () = assert!(#size_bits <= 8 * #size_bytes, #size_check_message);
+
+            impl ::bitfield::Serialize<{ #size_bytes }> for #enum_name {
                 type Type = #enum_name;
 
                 fn serialize(t: #enum_name) -> [u8; #size_bytes] {
-                    [t as u8]
+                    let mut bytes = [0; #size_bytes];
+                    bytes.copy_from_slice(&(t as u64).to_le_bytes()[..#size_bytes]);
+                    bytes
                 }
 
                 fn deserialize(bytes: [u8; #size_bytes]) -> #enum_name {
-                    match bytes[0] as u32 {
+                    let mut value_bytes = [0; 8];
+                    value_bytes[..#size_bytes].copy_from_slice(&bytes);
+                    match u64::from_le_bytes(value_bytes) {
                         #(#deserialize_match_arms)*
                         value => panic!(#panic_string, value)
                     }
                 }
+
+                fn try_deserialize_field(bytes: [u8; #size_bytes], _bit_count: usize) -> ::core::result::Result<#enum_name, ::bitfield::InvalidDiscriminant> {
+                    let mut value_bytes = [0; 8];
+                    value_bytes[..#size_bytes].copy_from_slice(&bytes);
+                    match u64::from_le_bytes(value_bytes) {
+                        #(#try_deserialize_match_arms)*
+                        value => ::core::result::Result::Err(::bitfield::InvalidDiscriminant::new(value))
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#enum_name> for #integer_type {
+                fn from(t: #enum_name) -> #integer_type {
+                    t as #integer_type
+                }
+            }
+
+            impl ::core::convert::TryFrom<#integer_type> for #enum_name {
+                type Error = ::bitfield::InvalidDiscriminant;
+
+                fn try_from(value: #integer_type) -> ::core::result::Result<#enum_name, ::bitfield::InvalidDiscriminant> {
+                    match value {
+                        #(#try_from_match_arms)*
+                        value => ::core::result::Result::Err(::bitfield::InvalidDiscriminant::new(value as u64))
+                    }
+                }
             }
         }.into())
     } else {
@@ -66,21 +168,57 @@ pub fn bitfield_specifier_derive_impl(input: TokenStream) -> syn::Result<TokenSt
     }
 }
 
-fn enum_variants(variants: Punctuated<Variant, Comma>) -> syn::Result<HashMap<Ident, u32>> {
+// Parses the optional `#[bitfield_specifier(bits = N)]` attribute, which fixes the width of the
+// specifier instead of deriving it from the largest discriminant.
+fn specifier_bits_from_attributes(attrs: &[Attribute]) -> syn::Result<Option<LitInt>> {
+    let mut bits = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("bitfield_specifier")) {
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let key: Ident = input.parse()?;
+            if key != "bits" {
+                return Err(syn::Error::new(key.span(), "expected 'bits'"));
+            }
+            if bits.is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate 'bits' option"));
+            }
+
+            let _: Token![=] = input.parse()?;
+            bits = Some(input.parse::<LitInt>()?);
+            Ok(())
+        })?;
+    }
+
+    Ok(bits)
+}
+
+fn enum_variants(variants: Punctuated<Variant, Comma>) -> syn::Result<HashMap<Ident, u64>> {
     let mut hashmap = HashMap::new();
 
+    // Like Rust itself, a variant without a discriminant takes the value after the previous one
+    let mut next_value: Option<u64> = Some(0);
+
+    // `base10_parse` gives the numeric value of any integer literal, whether it is written in
+    // decimal, hex, octal or binary, with or without underscores and a type suffix. The width of
+    // the specifier is computed from these values, never from the literal's text.
     for variant in variants.iter() {
-        if_chain! {
-            if let Some((_, discriminant)) = &variant.discriminant;
-            if let Expr::Lit(discriminant) = discriminant;
-            if let Lit::Int(discriminant) = &discriminant.lit;
-            if let Ok(value) = discriminant.base10_parse::<u32>();
-            then {
-                hashmap.insert(variant.ident.clone(), value);
-            } else {
-                return Err(syn::Error::new(variant.ident.span(), "every variant in an `BitfieldSpecifier` enum must have an explicit integer discriminant"));
-            }
-        }
+        let value = match &variant.discriminant {
+            Some((_, Expr::Lit(ExprLit { lit: Lit::Int(discriminant), .. }))) => discriminant.base10_parse::<u64>()?,
+            Some((_, discriminant @ Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. })))
+                if matches!(expr.as_ref(), Expr::Lit(ExprLit { lit: Lit::Int(_), .. })) => {
+                return Err(syn::Error::new_spanned(discriminant, "BitfieldSpecifier discriminants must be non-negative"));
+            },
+            Some((_, discriminant)) => {
+                return Err(syn::Error::new_spanned(discriminant, "an explicit discriminant in a `BitfieldSpecifier` enum must be an integer literal"));
+            },
+            None => match next_value {
+                Some(value) => value,
+                None => return Err(syn::Error::new(variant.ident.span(), "implicit discriminant of this variant overflows `u64`")),
+            },
+        };
+
+        hashmap.insert(variant.ident.clone(), value);
+        next_value = value.checked_add(1);
     }
 
     Ok(hashmap)
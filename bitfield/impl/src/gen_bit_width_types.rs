@@ -1,29 +1,48 @@
 use proc_macro::TokenStream;
 use quote::{quote, format_ident};
 use syn::{
-    parse::{Parse, ParseStream}, LitInt, Token,
+    parse::{Parse, ParseStream}, Ident, LitInt, LitStr, Token, Type,
 };
 
 pub fn gen_bit_width_types_impl(input: TokenStream) -> syn::Result<TokenStream> {
-    let GenBitWidthTypesInput { start, end } = syn::parse(input)?;
-    assert!(end >= start);
+    let GenBitWidthTypesInput { signed, prefix, accessor, start, end } = syn::parse(input)?;
     let type_count = end - start + 1;
 
-    let mut types = Vec::new();
-    types.reserve(type_count.into());
+    let mut types = Vec::with_capacity(type_count);
+
+    let (default_type_prefix, accessor_type_prefix) = if signed { ("I", "i") } else { ("B", "u") };
+    let type_prefix = prefix.as_deref().unwrap_or(default_type_prefix);
 
     for bit_width in start..=end {
-        let type_name = format_ident!("B{}", bit_width);
-        let accessor_type_size = std::cmp::max(bit_width.next_power_of_two(), 8);
-        let accessor_type_name = format_ident!("u{}", accessor_type_size);
+        let type_name = format_ident!("{}{}", type_prefix, bit_width);
+
+        let (accessor_type, accessor_check) = match &accessor {
+            // A forced accessor has to be checked once its size is known to the compiler
+            Some(accessor) => {
+                let message = format!("`{}` is too narrow for {} bits", quote!(#accessor), bit_width);
+                (
+                    quote!(#accessor),
+                    quote! {
+                        const _: () = assert!(#bit_width <= 8 * std::mem::size_of::<#accessor>(), #message);
+                    },
+                )
+            },
+            None => {
+                let accessor_type_size = std::cmp::max(bit_width.next_power_of_two(), 8);
+                let accessor_type_name = format_ident!("{}{}", accessor_type_prefix, accessor_type_size);
+                (quote!(#accessor_type_name), quote!())
+            },
+        };
 
         types.push(quote!{
             pub enum #type_name {}
 
             impl Specifier for #type_name {
                 const BITS: usize = #bit_width;
-                type ACCESSOR = #accessor_type_name;
+                type ACCESSOR = #accessor_type;
             }
+
+            #accessor_check
         });
     }
 
@@ -33,12 +52,47 @@ pub fn gen_bit_width_types_impl(input: TokenStream) -> syn::Result<TokenStream>
 }
 
 struct GenBitWidthTypesInput {
+    signed: bool,
+    prefix: Option<String>,
+    accessor: Option<Type>,
     start: usize,
     end: usize,
 }
 
 impl Parse for GenBitWidthTypesInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let signed = if input.peek(Ident) && !input.peek2(Token![=]) {
+            let keyword: Ident = input.parse()?;
+            if keyword != "signed" {
+                return Err(syn::Error::new(keyword.span(), "expected 'signed' or a range"));
+            }
+            true
+        } else {
+            false
+        };
+
+        let mut prefix = None;
+        let mut accessor = None;
+
+        while input.peek(Ident) {
+            let key: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+
+            if key == "prefix" {
+                let prefix_lit: LitStr = input.parse()?;
+                if syn::parse_str::<Ident>(&prefix_lit.value()).is_err() {
+                    return Err(syn::Error::new(prefix_lit.span(), "'prefix' must be a valid identifier"));
+                }
+                prefix = Some(prefix_lit.value());
+            } else if key == "accessor" {
+                accessor = Some(input.parse::<Type>()?);
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'prefix' or 'accessor'"));
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
         let start = input.parse::<LitInt>()?.base10_parse::<usize>()?;
 
         let _: Token![..=] = input.parse()?;
@@ -49,6 +103,6 @@ impl Parse for GenBitWidthTypesInput {
             return Err(input.error("'end' must be greater than or equal to 'start'"));
         }
 
-        Ok(Self { start, end })
+        Ok(Self { signed, prefix, accessor, start, end })
     }
 }
\ No newline at end of file
@@ -10,14 +10,14 @@ mod gen_bit_width_types;
 use crate::gen_bit_width_types::gen_bit_width_types_impl;
 
 #[proc_macro_attribute]
-pub fn bitfield(_args: TokenStream, input: TokenStream) -> TokenStream {
-    match bitfield_impl(input) {
+pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
+    match bitfield_impl(args, input) {
         Ok(output) => output,
         Err(err) => err.to_compile_error().into()
     }
 }
 
-#[proc_macro_derive(BitfieldSpecifier)]
+#[proc_macro_derive(BitfieldSpecifier, attributes(bitfield_specifier))]
 pub fn bitfield_specifier_derive(input: TokenStream) -> TokenStream {
     match bitfield_specifier_derive_impl(input) {
         Ok(output) => output,
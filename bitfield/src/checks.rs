@@ -1,3 +1,8 @@
+#[diagnostic::on_unimplemented(
+    message = "total bitfield size must be a multiple of 8 bits",
+    label = "the total size of this bitfield in bits is {Self}",
+    note = "add or widen fields so that their widths add up to a multiple of 8",
+)]
 pub trait TotalSizeIsMultipleOfEightBits {}
 
 pub struct ZeroMod8 {}
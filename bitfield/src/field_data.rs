@@ -1,14 +1,17 @@
 /// Returns a byte consisting of 1's in the bits [bit_start_index .. bit_start_index + bit_count],
 /// and 0's otherwise.
-/// Note that the leftmost bit is the 0th bit index.
+/// Note that the rightmost (least significant) bit is the 0th bit index.
 fn create_bit_mask(bit_start_index: usize, bit_count: usize) -> u8 {
     assert!(bit_start_index < 8);
     assert!(bit_start_index + bit_count <= 8);
 
-    let mask_usize: usize = ((1 << bit_count) - 1) << (8 - bit_start_index - bit_count);
+    let mask_usize: usize = ((1 << bit_count) - 1) << bit_start_index;
     mask_usize.try_into().unwrap()
 }
 
+/// Copies `bit_count` bits between two byte arrays. Bit `n` of an array is bit `n % 8` of byte
+/// `n / 8`, counting from the least significant bit, so a run of bits can cross any number of
+/// byte boundaries in either array.
 pub fn copy_bits(
     source_data: &[u8],
     destination_data: &mut [u8],
@@ -30,26 +33,18 @@ pub fn copy_bits(
         let destination_mask = create_bit_mask(current_destination_bit_index % 8, chunk_bit_count);
         let source_mask = create_bit_mask(current_source_bit_index % 8, chunk_bit_count);
 
-        let bit_offset = (isize::try_from(current_destination_bit_index % 8).unwrap() - isize::try_from(current_source_bit_index % 8).unwrap()) % 8;
-    
         let destination_byte = &mut destination_data[current_destination_bit_index / 8];
-        *destination_byte = *destination_byte & !destination_mask;
+        *destination_byte &= !destination_mask;
 
+        // Move the chunk down to bit 0, then up to its position in the destination byte
         let source_byte = source_data[current_source_bit_index / 8];
-        let source_data = source_byte & source_mask;
-
-        let shifted_source_data =
-            if bit_offset > 0 {
-                source_data >> bit_offset
-            } else {
-                source_data << -bit_offset
-            };
+        let chunk = (source_byte & source_mask) >> (current_source_bit_index % 8);
 
-        *destination_byte = *destination_byte | shifted_source_data;
+        *destination_byte |= chunk << (current_destination_bit_index % 8);
 
-        current_destination_bit_index = current_destination_bit_index + chunk_bit_count;
-        current_source_bit_index = current_source_bit_index + chunk_bit_count;
-        remaining_bit_count = remaining_bit_count - chunk_bit_count;
+        current_destination_bit_index += chunk_bit_count;
+        current_source_bit_index += chunk_bit_count;
+        remaining_bit_count -= chunk_bit_count;
     }
 }
 
@@ -62,17 +57,13 @@ pub fn get_field_data<const FIELD_DATA_BYTE_COUNT: usize>(
 
     let mut field_data: [u8; FIELD_DATA_BYTE_COUNT] = [0; FIELD_DATA_BYTE_COUNT];
 
-    // We use little-endian byte ordering, so unused bytes in the field data should be at the end
-    // of the field_data array.
-    // We also want the right-most bit of the data to align with the right-most bit of the
-    // last non-empty byte.
-    let field_data_bit_start_index = ((FIELD_DATA_BYTE_COUNT * 8) - bit_count) % 8;
-
+    // The field data is the field's value in little-endian byte order, so the field's bits start
+    // at the least significant bit of the first byte, however many bitfield bytes they span.
     copy_bits(
-        &bitfield_data,
+        bitfield_data,
         &mut field_data,
         bit_start_index /*source_bit_start_index*/,
-        field_data_bit_start_index /*destination_bit_start_index*/,
+        0 /*destination_bit_start_index*/,
         bit_count,
     );
 
@@ -85,23 +76,117 @@ pub fn set_field_data<const FIELD_DATA_BYTE_COUNT: usize>(
     bit_start_index: usize,
     bit_count: usize,
 ) {
-    // TODO: De-dupe with get_field_data?
-
-    // We use little-endian byte ordering, so unused bytes in the field data should be at the end
-    // of the field_data array.
-    // We also want the right-most bit of the data to align with the right-most bit of the
-    // last non-empty byte.
-    let field_data_bit_start_index = ((FIELD_DATA_BYTE_COUNT * 8) - bit_count) % 8;
-
+    // The inverse of get_field_data: only the `bit_count` least significant bits of the field
+    // data are written.
     copy_bits(
         &field_data,
         bitfield_data,
-        field_data_bit_start_index /*source_bit_start_index*/,
+        0 /*source_bit_start_index*/,
         bit_start_index /*destination_bit_start_index*/,
         bit_count,
     );
 }
 
+/// How the bits of a bitfield are numbered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Bit 0 is the least significant bit of the first byte, and a field's least significant bit
+    /// comes first.
+    LsbFirst,
+    /// Bit 0 is the most significant bit of the first byte, and a field's most significant bit
+    /// comes first.
+    MsbFirst,
+}
+
+fn get_bit_msb_first(data: &[u8], bit_index: usize) -> bool {
+    data[bit_index / 8] & (0b10000000 >> (bit_index % 8)) != 0
+}
+
+fn set_bit_msb_first(data: &mut [u8], bit_index: usize, value: bool) {
+    let mask = 0b10000000 >> (bit_index % 8);
+    if value {
+        data[bit_index / 8] |= mask;
+    } else {
+        data[bit_index / 8] &= !mask;
+    }
+}
+
+pub fn get_field_data_in_order<const FIELD_DATA_BYTE_COUNT: usize>(
+    bitfield_data: &[u8],
+    bit_start_index: usize,
+    bit_count: usize,
+    bit_order: BitOrder,
+) -> [u8; FIELD_DATA_BYTE_COUNT] {
+    match bit_order {
+        BitOrder::LsbFirst => get_field_data(bitfield_data, bit_start_index, bit_count),
+        BitOrder::MsbFirst => {
+            let mut field_data: [u8; FIELD_DATA_BYTE_COUNT] = [0; FIELD_DATA_BYTE_COUNT];
+
+            // The last bit of the field is the least significant bit of its value
+            for value_bit_index in 0..bit_count {
+                if get_bit_msb_first(bitfield_data, bit_start_index + bit_count - 1 - value_bit_index) {
+                    field_data[value_bit_index / 8] |= 1 << (value_bit_index % 8);
+                }
+            }
+
+            field_data
+        },
+    }
+}
+
+pub fn set_field_data_in_order<const FIELD_DATA_BYTE_COUNT: usize>(
+    bitfield_data: &mut [u8],
+    field_data: [u8; FIELD_DATA_BYTE_COUNT],
+    bit_start_index: usize,
+    bit_count: usize,
+    bit_order: BitOrder,
+) {
+    match bit_order {
+        BitOrder::LsbFirst => set_field_data(bitfield_data, field_data, bit_start_index, bit_count),
+        BitOrder::MsbFirst => {
+            for value_bit_index in 0..bit_count {
+                let value = field_data[value_bit_index / 8] & (1 << (value_bit_index % 8)) != 0;
+                set_bit_msb_first(bitfield_data, bit_start_index + bit_count - 1 - value_bit_index, value);
+            }
+        },
+    }
+}
+
+/// Like `get_field_data_in_order`, but with the bit count as a const parameter, so that a field
+/// too wide for its field data is a compile-time error instead of an out-of-bounds write.
+pub fn get_field_data_sized<const FIELD_DATA_BYTE_COUNT: usize, const BIT_COUNT: usize>(
+    bitfield_data: &[u8],
+    bit_start_index: usize,
+    bit_order: BitOrder,
+) -> [u8; FIELD_DATA_BYTE_COUNT] {
+    const { assert!(BIT_COUNT <= FIELD_DATA_BYTE_COUNT * 8, "field is wider than its field data") };
+
+    get_field_data_in_order::<FIELD_DATA_BYTE_COUNT>(bitfield_data, bit_start_index, BIT_COUNT, bit_order)
+}
+
+/// Like `set_field_data_in_order`, but with the bit count as a const parameter, so that only the
+/// bits that fit in the field data can ever be written.
+pub fn set_field_data_sized<const FIELD_DATA_BYTE_COUNT: usize, const BIT_COUNT: usize>(
+    bitfield_data: &mut [u8],
+    field_data: [u8; FIELD_DATA_BYTE_COUNT],
+    bit_start_index: usize,
+    bit_order: BitOrder,
+) {
+    const { assert!(BIT_COUNT <= FIELD_DATA_BYTE_COUNT * 8, "field is wider than its field data") };
+
+    set_field_data_in_order::<FIELD_DATA_BYTE_COUNT>(bitfield_data, field_data, bit_start_index, BIT_COUNT, bit_order);
+}
+
+/// Reverses the order of the bytes holding a field's `bit_count` bits, converting between the
+/// little-endian field data and a big-endian field. `bit_count` must be a multiple of 8.
+pub fn reverse_field_bytes<const FIELD_DATA_BYTE_COUNT: usize>(
+    mut field_data: [u8; FIELD_DATA_BYTE_COUNT],
+    bit_count: usize,
+) -> [u8; FIELD_DATA_BYTE_COUNT] {
+    field_data[..bit_count / 8].reverse();
+    field_data
+}
+
 #[cfg(test)]
 mod tests {
     use crate::field_data::*;
@@ -111,32 +196,32 @@ mod tests {
         // bit_count == 1, single byte
         assert_eq!(get_field_data::<1>(&[0b10110001], 0 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
         assert_eq!(get_field_data::<1>(&[0b10110001], 1 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
-        assert_eq!(get_field_data::<1>(&[0b10110001], 2 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
-        assert_eq!(get_field_data::<1>(&[0b10110001], 3 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
-        assert_eq!(get_field_data::<1>(&[0b10110001], 4 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
-        assert_eq!(get_field_data::<1>(&[0b10110001], 5 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
+        assert_eq!(get_field_data::<1>(&[0b10110001], 2 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
+        assert_eq!(get_field_data::<1>(&[0b10110001], 3 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
+        assert_eq!(get_field_data::<1>(&[0b10110001], 4 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001], 5 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
         assert_eq!(get_field_data::<1>(&[0b10110001], 6 /*bit_start_index*/, 1 /*bit_count*/), [0b00000000]);
         assert_eq!(get_field_data::<1>(&[0b10110001], 7 /*bit_start_index*/, 1 /*bit_count*/), [0b00000001]);
 
         // bit_count == 2, multiple bytes
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 0 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 1 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 2 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 0 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 1 /*bit_start_index*/, 2 /*bit_count*/), [0b00000000]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 2 /*bit_start_index*/, 2 /*bit_count*/), [0b00000000]);
         assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 3 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 4 /*bit_start_index*/, 2 /*bit_count*/), [0b00000000]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 5 /*bit_start_index*/, 2 /*bit_count*/), [0b00000000]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 6 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 4 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 5 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 6 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
         assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 7 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 8 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 9 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 10 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 8 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 9 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 10 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
         assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 11 /*bit_start_index*/, 2 /*bit_count*/), [0b00000000]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 12 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 13 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 14 /*bit_start_index*/, 2 /*bit_count*/), [0b00000001]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 12 /*bit_start_index*/, 2 /*bit_count*/), [0b00000010]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 13 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 14 /*bit_start_index*/, 2 /*bit_count*/), [0b00000011]);
         
         // bit_count == 6, multiple bytes
-        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 5 /*bit_start_index*/, 6 /*bit_count*/), [0b00001111]);
+        assert_eq!(get_field_data::<1>(&[0b10110001, 0b11100101], 5 /*bit_start_index*/, 6 /*bit_count*/), [0b00101101]);
 
         // Data spanning multiple bytes, aligned on byte boundaries
         assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 8 /*bit_count*/), [0b10110001, 0, 0]);
@@ -144,12 +229,12 @@ mod tests {
         assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 24 /*bit_count*/), [0b10110001, 0b11100101, 0b00101110]);
 
         // Similar to previous, but not *quite* byte-boundary-aligned
-        assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 1 /*bit_start_index*/, 23 /*bit_count*/), [0b00110001, 0b11100101, 0b00101110]);
-        assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 23 /*bit_count*/), [0b01011000, 0b11110010, 0b10010111]);
+        assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 1 /*bit_start_index*/, 23 /*bit_count*/), [0b11011000, 0b01110010, 0b00010111]);
+        assert_eq!(get_field_data::<3>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 23 /*bit_count*/), [0b10110001, 0b11100101, 0b00101110]);
 
         // Similar to previous, but using a larger field_data array than is needed
-        assert_eq!(get_field_data::<5>(&[0b10110001, 0b11100101, 0b00101110], 1 /*bit_start_index*/, 23 /*bit_count*/), [0b00110001, 0b11100101, 0b00101110, 0, 0]);
-        assert_eq!(get_field_data::<5>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 23 /*bit_count*/), [0b01011000, 0b11110010, 0b10010111, 0, 0]);
+        assert_eq!(get_field_data::<5>(&[0b10110001, 0b11100101, 0b00101110], 1 /*bit_start_index*/, 23 /*bit_count*/), [0b11011000, 0b01110010, 0b00010111, 0, 0]);
+        assert_eq!(get_field_data::<5>(&[0b10110001, 0b11100101, 0b00101110], 0 /*bit_start_index*/, 23 /*bit_count*/), [0b10110001, 0b11100101, 0b00101110, 0, 0]);
     }
 
     fn test_set_field_data<const BITFIELD_BYTE_COUNT: usize, const FIELD_DATA_BYTE_COUNT: usize>(
@@ -169,61 +254,61 @@ mod tests {
     fn set_field_data_tests() {
         // bit_count == 1, single byte, setting to 1
         test_set_field_data::<1, 1>([0b10110001], [0b00000001], 0 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 1 /*bit_start_index*/, 1 /*bit_count*/, [0b11110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 2 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 3 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 4 /*bit_start_index*/, 1 /*bit_count*/, [0b10111001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 5 /*bit_start_index*/, 1 /*bit_count*/, [0b10110101]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 6 /*bit_start_index*/, 1 /*bit_count*/, [0b10110011]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 1 /*bit_start_index*/, 1 /*bit_count*/, [0b10110011]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 2 /*bit_start_index*/, 1 /*bit_count*/, [0b10110101]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 3 /*bit_start_index*/, 1 /*bit_count*/, [0b10111001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 4 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 5 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000001], 6 /*bit_start_index*/, 1 /*bit_count*/, [0b11110001]);
         test_set_field_data::<1, 1>([0b10110001], [0b00000001], 7 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
 
         // bit_count == 1, single byte, setting to 0
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 0 /*bit_start_index*/, 1 /*bit_count*/, [0b00110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 0 /*bit_start_index*/, 1 /*bit_count*/, [0b10110000]);
         test_set_field_data::<1, 1>([0b10110001], [0b00000000], 1 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 2 /*bit_start_index*/, 1 /*bit_count*/, [0b10010001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 3 /*bit_start_index*/, 1 /*bit_count*/, [0b10100001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 4 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 5 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 2 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 3 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 4 /*bit_start_index*/, 1 /*bit_count*/, [0b10100001]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 5 /*bit_start_index*/, 1 /*bit_count*/, [0b10010001]);
         test_set_field_data::<1, 1>([0b10110001], [0b00000000], 6 /*bit_start_index*/, 1 /*bit_count*/, [0b10110001]);
-        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 7 /*bit_start_index*/, 1 /*bit_count*/, [0b10110000]);
+        test_set_field_data::<1, 1>([0b10110001], [0b00000000], 7 /*bit_start_index*/, 1 /*bit_count*/, [0b00110001]);
     
         // bit_count == 2, multiple bytes, setting to 1
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 0 /*bit_start_index*/, 2 /*bit_count*/,  [0b11110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 1 /*bit_start_index*/, 2 /*bit_count*/,  [0b11110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 2 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 0 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110011, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 1 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110111, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 2 /*bit_start_index*/, 2 /*bit_count*/,  [0b10111101, 0b11100101]);
         test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 3 /*bit_start_index*/, 2 /*bit_count*/,  [0b10111001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 4 /*bit_start_index*/, 2 /*bit_count*/,  [0b10111101, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 5 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110111, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 6 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110011, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 4 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 5 /*bit_start_index*/, 2 /*bit_count*/,  [0b11110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 6 /*bit_start_index*/, 2 /*bit_count*/,  [0b11110001, 0b11100101]);
         test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 7 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 8 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 9 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 10 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11110101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 8 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100111]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 9 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100111]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 10 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11101101]);
         test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 11 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11111101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 12 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11101101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 13 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100111]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 14 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100111]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 12 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11110101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 13 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000011], 14 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100101]);
     
         // bit_count == 2, multiple bytes, setting to 0
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 0 /*bit_start_index*/, 2 /*bit_count*/,  [0b00110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 1 /*bit_start_index*/, 2 /*bit_count*/,  [0b10010001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 2 /*bit_start_index*/, 2 /*bit_count*/,  [0b10000001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 0 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110000, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 1 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 2 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
         test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 3 /*bit_start_index*/, 2 /*bit_count*/,  [0b10100001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 4 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 5 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 6 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110000, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 7 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110000, 0b01100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 8 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b00100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 9 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b10000101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 10 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11000101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 4 /*bit_start_index*/, 2 /*bit_count*/,  [0b10000001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 5 /*bit_start_index*/, 2 /*bit_count*/,  [0b10010001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 6 /*bit_start_index*/, 2 /*bit_count*/,  [0b00110001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 7 /*bit_start_index*/, 2 /*bit_count*/,  [0b00110001, 0b11100100]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 8 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100100]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 9 /*bit_start_index*/, 2 /*bit_count*/,  [0b10110001, 0b11100001]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 10 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100001]);
         test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 11 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 12 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100001]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 13 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100001]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 14 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11100100]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 12 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b11000101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 13 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b10000101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00000000], 14 /*bit_start_index*/, 2 /*bit_count*/, [0b10110001, 0b00100101]);
     
         // bit_count == 6, multiple bytes
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00101010], 5 /*bit_start_index*/, 6 /*bit_count*/, [0b10110101, 0b01000101]);
-        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00010101], 5 /*bit_start_index*/, 6 /*bit_count*/, [0b10110010, 0b10100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00101010], 5 /*bit_start_index*/, 6 /*bit_count*/, [0b01010001, 0b11100101]);
+        test_set_field_data::<2, 1>([0b10110001, 0b11100101], [0b00010101], 5 /*bit_start_index*/, 6 /*bit_count*/, [0b10110001, 0b11100010]);
     
         // Data spanning multiple bytes, aligned on byte boundaries
         test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 0 /*bit_start_index*/, 8 /*bit_count*/, [0b10101010, 0b11100101, 0b00101110]);
@@ -231,7 +316,88 @@ mod tests {
         test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 0 /*bit_start_index*/, 24 /*bit_count*/, [0b10101010, 0b10101010, 0b10101010]);
     
         // Data spanning multiple bytes, but not *quite* byte-boundary-aligned
-        test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 1 /*bit_start_index*/, 23 /*bit_count*/, [0b10101010, 0b10101010, 0b10101010]);
-        test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 0 /*bit_start_index*/, 23 /*bit_count*/, [0b01010101, 0b01010101, 0b01010100]);
+        test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 1 /*bit_start_index*/, 23 /*bit_count*/, [0b01010101, 0b01010101, 0b01010101]);
+        test_set_field_data::<3, 3>([0b10110001, 0b11100101, 0b00101110], [0b10101010, 0b10101010, 0b10101010], 0 /*bit_start_index*/, 23 /*bit_count*/, [0b10101010, 0b10101010, 0b00101010]);
+    }
+
+    #[test]
+    fn field_data_round_trip_tests() {
+        // Fields at every bit offset within a byte, including ones like a 13-bit field starting at
+        // bit 5 whose bits span more bytes than its accessor has.
+        for bit_start_index in 0..8 {
+            for bit_count in 1..=64 {
+                let value: u64 = 0xa5c3_96e1_5a3c_691e & (u64::MAX >> (64 - bit_count));
+                let mut bitfield_data = [0b01010101; 10];
+
+                set_field_data::<8>(&mut bitfield_data, value.to_le_bytes(), bit_start_index, bit_count);
+                let field_data = get_field_data::<8>(&bitfield_data, bit_start_index, bit_count);
+                assert_eq!(u64::from_le_bytes(field_data), value, "bit_start_index {}, bit_count {}", bit_start_index, bit_count);
+
+                // The bits on either side of the field are untouched
+                let mut field_mask = [0; 10];
+                set_field_data::<8>(&mut field_mask, [0xff; 8], bit_start_index, bit_count);
+                for (byte, mask) in bitfield_data.iter().zip(field_mask) {
+                    assert_eq!(byte & !mask, 0b01010101 & !mask);
+                }
+            }
+        }
+
+        let mut bitfield_data = [0; 3];
+        set_field_data::<2>(&mut bitfield_data, 0x1675u16.to_le_bytes(), 5 /*bit_start_index*/, 13 /*bit_count*/);
+        assert_eq!(bitfield_data, [0b10100000, 0b11001110, 0b00000010]);
+        assert_eq!(get_field_data::<2>(&bitfield_data, 5 /*bit_start_index*/, 13 /*bit_count*/), 0x1675u16.to_le_bytes());
+    }
+
+    #[test]
+    fn msb_first_field_data_tests() {
+        // The first bit is the most significant bit of the first byte
+        assert_eq!(get_field_data_in_order::<1>(&[0b10110001], 0 /*bit_start_index*/, 1 /*bit_count*/, BitOrder::MsbFirst), [0b00000001]);
+        assert_eq!(get_field_data_in_order::<1>(&[0b10110001], 1 /*bit_start_index*/, 3 /*bit_count*/, BitOrder::MsbFirst), [0b00000011]);
+
+        // A field's most significant bit comes first, across byte boundaries
+        assert_eq!(get_field_data_in_order::<2>(&[0b01011010, 0b10111100], 4 /*bit_start_index*/, 12 /*bit_count*/, BitOrder::MsbFirst), 0xabcu16.to_le_bytes());
+
+        let mut bitfield_data = [0b01010000, 0];
+        set_field_data_in_order::<2>(&mut bitfield_data, 0xabcu16.to_le_bytes(), 4 /*bit_start_index*/, 12 /*bit_count*/, BitOrder::MsbFirst);
+        assert_eq!(bitfield_data, [0b01011010, 0b10111100]);
+
+        for bit_start_index in 0..8 {
+            for bit_count in 1..=32 {
+                let value: u32 = 0x96e1_5a3c & (u32::MAX >> (32 - bit_count));
+                let mut bitfield_data = [0b01010101; 6];
+
+                set_field_data_in_order::<4>(&mut bitfield_data, value.to_le_bytes(), bit_start_index, bit_count, BitOrder::MsbFirst);
+                let field_data = get_field_data_in_order::<4>(&bitfield_data, bit_start_index, bit_count, BitOrder::MsbFirst);
+                assert_eq!(u32::from_le_bytes(field_data), value, "bit_start_index {}, bit_count {}", bit_start_index, bit_count);
+            }
+        }
+    }
+
+    fn check_sized_field_data<const FIELD_DATA_BYTE_COUNT: usize, const BIT_COUNT: usize>() {
+        let bitfield_data = [0b10110001, 0b11100101, 0b00101110, 0b01011100, 0b10111001, 0b01110010, 0b11100101, 0b11001010, 0b10010101, 0b00101011];
+
+        for bit_order in [BitOrder::LsbFirst, BitOrder::MsbFirst] {
+            for bit_start_index in 0..=(bitfield_data.len() * 8 - BIT_COUNT) {
+                let field_data = get_field_data_sized::<FIELD_DATA_BYTE_COUNT, BIT_COUNT>(&bitfield_data, bit_start_index, bit_order);
+                assert_eq!(field_data, get_field_data_in_order::<FIELD_DATA_BYTE_COUNT>(&bitfield_data, bit_start_index, BIT_COUNT, bit_order));
+
+                let mut sized_bitfield_data = [0b01010101; 10];
+                let mut runtime_bitfield_data = [0b01010101; 10];
+                set_field_data_sized::<FIELD_DATA_BYTE_COUNT, BIT_COUNT>(&mut sized_bitfield_data, field_data, bit_start_index, bit_order);
+                set_field_data_in_order::<FIELD_DATA_BYTE_COUNT>(&mut runtime_bitfield_data, field_data, bit_start_index, BIT_COUNT, bit_order);
+                assert_eq!(sized_bitfield_data, runtime_bitfield_data);
+            }
+        }
+    }
+
+    #[test]
+    fn sized_field_data_tests() {
+        check_sized_field_data::<1, 1>();
+        check_sized_field_data::<1, 3>();
+        check_sized_field_data::<1, 8>();
+        check_sized_field_data::<2, 13>();
+        check_sized_field_data::<4, 24>();
+        check_sized_field_data::<8, 57>();
+        check_sized_field_data::<16, 80>();
     }
 }
\ No newline at end of file
@@ -17,12 +17,23 @@ pub use bitfield_impl::bitfield;
 pub use bitfield_impl::BitfieldSpecifier;
 use bitfield_impl::gen_bit_width_types;
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as the type of a bitfield field",
+    label = "not a bitfield specifier",
+    note = "a field can be a `B1`..`B128` or `I1`..`I128` type, `bool`, a `BitfieldSpecifier` enum, an array of these, or another `#[bitfield]` struct without `#[skip]` fields",
+)]
 pub trait Specifier {
     const BITS: usize;
     type ACCESSOR;
 }
 
-gen_bit_width_types!(1..=64);
+gen_bit_width_types!(1..=128);
+gen_bit_width_types!(signed 1..=128);
+
+impl<T: Specifier, const N: usize> Specifier for [T; N] {
+    const BITS: usize = T::BITS * N;
+    type ACCESSOR = [T::ACCESSOR; N];
+}
 
 impl Specifier for bool {
     const BITS: usize = 1;
@@ -34,8 +45,47 @@ pub trait Serialize<const SIZE: usize> {
 
     fn serialize(t: Self::Type) -> [u8; SIZE];
     fn deserialize(bytes: [u8; SIZE]) -> Self::Type;
+
+    // Field data only holds `bit_count` significant bits, which signed types need to know about
+    // in order to sign-extend a field narrower than `Self::Type`.
+    fn deserialize_field(bytes: [u8; SIZE], bit_count: usize) -> Self::Type {
+        let _ = bit_count;
+        Self::deserialize(bytes)
+    }
+
+    // Like `deserialize_field`, but reports field data that doesn't correspond to any value of
+    // `Self::Type` instead of panicking. Only enums can have such values.
+    fn try_deserialize_field(bytes: [u8; SIZE], bit_count: usize) -> Result<Self::Type, InvalidDiscriminant> {
+        Ok(Self::deserialize_field(bytes, bit_count))
+    }
+}
+
+/// The error returned by a checked getter when the bits of an enum field don't match any of
+/// the enum's discriminants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDiscriminant {
+    value: u64,
+}
+
+impl InvalidDiscriminant {
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// The raw value that was found in the field.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl std::fmt::Display for InvalidDiscriminant {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "invalid discriminant: {}", self.value)
+    }
 }
 
+impl std::error::Error for InvalidDiscriminant {}
+
 impl Serialize<1> for bool {
     type Type = bool;
 
@@ -94,4 +144,132 @@ impl Serialize<8> for u64 {
     fn deserialize(bytes: [u8; 8]) -> u64 {
         u64::from_le_bytes(bytes)
     }
+}
+
+impl Serialize<16> for u128 {
+    type Type = u128;
+
+    fn serialize(t: u128) -> [u8; 16] {
+        t.to_le_bytes()
+    }
+
+    fn deserialize(bytes: [u8; 16]) -> u128 {
+        u128::from_le_bytes(bytes)
+    }
+}
+
+impl Serialize<1> for i8 {
+    type Type = i8;
+
+    fn serialize(t: i8) -> [u8; 1] {
+        [t as u8]
+    }
+
+    fn deserialize(bytes: [u8; 1]) -> i8 {
+        bytes[0] as i8
+    }
+
+    fn deserialize_field(bytes: [u8; 1], bit_count: usize) -> i8 {
+        let unused_bit_count = 8 - bit_count;
+        (Self::deserialize(bytes) << unused_bit_count) >> unused_bit_count
+    }
+}
+
+impl Serialize<2> for i16 {
+    type Type = i16;
+
+    fn serialize(t: i16) -> [u8; 2] {
+        t.to_le_bytes()
+    }
+
+    fn deserialize(bytes: [u8; 2]) -> i16 {
+        i16::from_le_bytes(bytes)
+    }
+
+    fn deserialize_field(bytes: [u8; 2], bit_count: usize) -> i16 {
+        let unused_bit_count = 16 - bit_count;
+        (Self::deserialize(bytes) << unused_bit_count) >> unused_bit_count
+    }
+}
+
+impl Serialize<4> for i32 {
+    type Type = i32;
+
+    fn serialize(t: i32) -> [u8; 4] {
+        t.to_le_bytes()
+    }
+
+    fn deserialize(bytes: [u8; 4]) -> i32 {
+        i32::from_le_bytes(bytes)
+    }
+
+    fn deserialize_field(bytes: [u8; 4], bit_count: usize) -> i32 {
+        let unused_bit_count = 32 - bit_count;
+        (Self::deserialize(bytes) << unused_bit_count) >> unused_bit_count
+    }
+}
+
+impl Serialize<8> for i64 {
+    type Type = i64;
+
+    fn serialize(t: i64) -> [u8; 8] {
+        t.to_le_bytes()
+    }
+
+    fn deserialize(bytes: [u8; 8]) -> i64 {
+        i64::from_le_bytes(bytes)
+    }
+
+    fn deserialize_field(bytes: [u8; 8], bit_count: usize) -> i64 {
+        let unused_bit_count = 64 - bit_count;
+        (Self::deserialize(bytes) << unused_bit_count) >> unused_bit_count
+    }
+}
+
+impl Serialize<16> for i128 {
+    type Type = i128;
+
+    fn serialize(t: i128) -> [u8; 16] {
+        t.to_le_bytes()
+    }
+
+    fn deserialize(bytes: [u8; 16]) -> i128 {
+        i128::from_le_bytes(bytes)
+    }
+
+    fn deserialize_field(bytes: [u8; 16], bit_count: usize) -> i128 {
+        let unused_bit_count = 128 - bit_count;
+        (Self::deserialize(bytes) << unused_bit_count) >> unused_bit_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Specifier;
+    use bitfield_impl::gen_bit_width_types;
+    use std::mem::size_of;
+
+    gen_bit_width_types!(prefix = "Bits", 1..=32);
+    gen_bit_width_types!(signed prefix = "SignedBits", 1..=16);
+    gen_bit_width_types!(prefix = "Wide", accessor = u64, 1..=8);
+
+    #[test]
+    fn custom_prefix_tests() {
+        assert_eq!(<Bits1 as Specifier>::BITS, 1);
+        assert_eq!(<Bits13 as Specifier>::BITS, 13);
+        assert_eq!(<Bits32 as Specifier>::BITS, 32);
+        assert_eq!(size_of::<<Bits13 as Specifier>::ACCESSOR>(), 2);
+
+        assert_eq!(<SignedBits5 as Specifier>::BITS, 5);
+        let value: <SignedBits5 as Specifier>::ACCESSOR = -1i8;
+        assert_eq!(value, -1);
+    }
+
+    #[test]
+    fn custom_accessor_tests() {
+        assert_eq!(<Wide3 as Specifier>::BITS, 3);
+        let value: <Wide3 as Specifier>::ACCESSOR = 5u64;
+        assert_eq!(value, 5);
+        assert_eq!(size_of::<<Wide8 as Specifier>::ACCESSOR>(), 8);
+    }
 }
\ No newline at end of file
@@ -36,8 +36,8 @@ fn main() {
     assert_eq!(0, bitfield.get_d());
 
     bitfield.set_c(14);
-    //assert_eq!(0, bitfield.get_a());
-    //assert_eq!(0, bitfield.get_b());
-    //assert_eq!(14, bitfield.get_c());
-    //assert_eq!(0, bitfield.get_d());
+    assert_eq!(0, bitfield.get_a());
+    assert_eq!(0, bitfield.get_b());
+    assert_eq!(14, bitfield.get_c());
+    assert_eq!(0, bitfield.get_d());
 }
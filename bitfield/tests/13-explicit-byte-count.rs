@@ -0,0 +1,21 @@
+// A bitfield can state its total size up front with `#[bitfield(bytes = N)]`.
+// The storage array then has exactly N bytes, and the fields are checked to add
+// up to N * 8 bits.
+
+use bitfield::*;
+
+#[bitfield(bytes = 4)]
+pub struct MyFourBytes {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+fn main() {
+    assert_eq!(std::mem::size_of::<MyFourBytes>(), 4);
+
+    let mut bitfield = MyFourBytes::new();
+    bitfield.set_d(0x00ab_cdef);
+    assert_eq!(0x00ab_cdef, bitfield.get_d());
+}
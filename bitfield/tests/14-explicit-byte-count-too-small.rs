@@ -0,0 +1,12 @@
+// The fields of a bitfield with an explicit byte count must fill that many
+// bytes exactly. Here they need 5 bytes but only 4 are declared.
+
+use bitfield::*;
+
+#[bitfield(bytes = 4)]
+pub struct MyFiveBytes {
+    a: B8,
+    b: B32,
+}
+
+fn main() {}
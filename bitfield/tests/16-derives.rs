@@ -0,0 +1,34 @@
+// `#[bitfield(derive(...))]` adds trait impls to the generated struct. Clone
+// and Copy are derived as usual, while Debug is written out by hand so that it
+// shows each field through its getter instead of the raw storage bytes.
+
+use bitfield::*;
+
+#[bitfield(derive(Clone, Copy, Debug))]
+pub struct RedirectionTableEntry {
+    acknowledged: bool,
+    trigger_mode: TriggerMode,
+    reserved: B6,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum TriggerMode {
+    Edge = 0,
+    Level = 1,
+}
+
+fn main() {
+    let mut entry = RedirectionTableEntry::new();
+    entry.set_acknowledged(true);
+    entry.set_reserved(5);
+
+    let copy = entry;
+    let clone = copy.clone();
+    assert_eq!(entry.get_reserved(), 5);
+    assert_eq!(clone.get_acknowledged(), true);
+
+    assert_eq!(
+        format!("{:?}", clone),
+        "RedirectionTableEntry { acknowledged: true, trigger_mode: Edge, reserved: 5 }",
+    );
+}
@@ -0,0 +1,38 @@
+// Signed fields use the I1 through I64 specifiers, whose accessors are the
+// narrowest signed integer type that can hold the bits. A negative value read
+// back from a field narrower than its accessor must be sign-extended.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct SignedFields {
+    a: I5,
+    b: I3,
+    c: B8,
+    d: I16,
+}
+
+fn main() {
+    let mut bitfield = SignedFields::new();
+    assert_eq!(0, bitfield.get_a());
+    assert_eq!(0, bitfield.get_d());
+
+    bitfield.set_a(-11);
+    bitfield.set_b(-1);
+    bitfield.set_c(0xff);
+    bitfield.set_d(-12345);
+    assert_eq!(-11, bitfield.get_a());
+    assert_eq!(-1, bitfield.get_b());
+    assert_eq!(0xff, bitfield.get_c());
+    assert_eq!(-12345, bitfield.get_d());
+
+    bitfield.set_a(15);
+    bitfield.set_b(-4);
+    assert_eq!(15, bitfield.get_a());
+    assert_eq!(-4, bitfield.get_b());
+    assert_eq!(0xff, bitfield.get_c());
+
+    let a: i8 = bitfield.get_a();
+    let d: i16 = bitfield.get_d();
+    assert_eq!((a, d), (15, -12345));
+}
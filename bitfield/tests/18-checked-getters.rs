@@ -0,0 +1,34 @@
+// Every field also gets a `get_*_checked` getter. For an enum field it returns
+// an `InvalidDiscriminant` error carrying the raw value when the bits don't
+// match any variant, where the plain getter would panic.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct RedirectionTableEntry {
+    delivery_mode: DeliveryMode,
+    reserved: B5,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum DeliveryMode {
+    Lowest = 1,
+    Smi = 2,
+    RemoteRead = 3,
+    Nmi = 4,
+}
+
+fn main() {
+    // All zero bits, which isn't a valid delivery mode.
+    let mut entry = RedirectionTableEntry::new();
+    let error = entry.get_delivery_mode_checked().unwrap_err();
+    assert_eq!(error.value(), 0);
+    assert_eq!(error.to_string(), "invalid discriminant: 0");
+
+    entry.set_delivery_mode(DeliveryMode::RemoteRead);
+    assert_eq!(entry.get_delivery_mode_checked(), Ok(DeliveryMode::RemoteRead));
+    assert_eq!(entry.get_delivery_mode(), DeliveryMode::RemoteRead);
+
+    entry.set_reserved(31);
+    assert_eq!(entry.get_reserved_checked(), Ok(31));
+}
@@ -0,0 +1,32 @@
+// The constructor and accessors have the same visibility as the bitfield
+// struct, so a public bitfield can be used from other modules. A field can
+// declare its own visibility to override that of its accessors.
+
+mod registers {
+    use bitfield::*;
+
+    #[bitfield]
+    pub struct Status {
+        ready: bool,
+        pub(super) error_code: B7,
+    }
+}
+
+mod driver {
+    use crate::registers::Status;
+
+    pub fn ready_status() -> Status {
+        let mut status = Status::new();
+        status.set_ready(true);
+        status
+    }
+}
+
+fn main() {
+    let mut status = driver::ready_status();
+    assert_eq!(status.get_ready(), true);
+
+    status.set_error_code(42);
+    assert_eq!(status.get_error_code(), 42);
+    assert_eq!(status.get_error_code_checked(), Ok(42));
+}
@@ -0,0 +1,37 @@
+// Specifiers go up to B128 and I128, with u128 and i128 accessors, so a field
+// can be wider than 64 bits.
+
+use bitfield::*;
+use std::mem::size_of_val;
+
+#[bitfield]
+pub struct WideFields {
+    a: B8,
+    b: B96,
+    c: B16,
+    d: I72,
+    e: B56,
+}
+
+fn main() {
+    let mut bitfield = WideFields::new();
+    assert_eq!(0, bitfield.get_b());
+    assert_eq!(size_of_val(&bitfield.get_b()), 16);
+
+    let b = 0xfedc_ba98_7654_3210_0123_4567;
+    bitfield.set_a(0xaa);
+    bitfield.set_b(b);
+    bitfield.set_c(0x5555);
+    bitfield.set_d(-(1 << 70));
+    bitfield.set_e(0x00ff_ffff_ffff_ffff);
+    assert_eq!(0xaa, bitfield.get_a());
+    assert_eq!(b, bitfield.get_b());
+    assert_eq!(0x5555, bitfield.get_c());
+    assert_eq!(-(1 << 70), bitfield.get_d());
+    assert_eq!(0x00ff_ffff_ffff_ffff, bitfield.get_e());
+
+    bitfield.set_b(u128::from(u64::MAX) << 32);
+    assert_eq!(u128::from(u64::MAX) << 32, bitfield.get_b());
+    assert_eq!(0xaa, bitfield.get_a());
+    assert_eq!(0x5555, bitfield.get_c());
+}
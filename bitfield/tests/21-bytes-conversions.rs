@@ -0,0 +1,32 @@
+// A bitfield can be converted to and from its raw bytes with `from_bytes`,
+// `as_bytes` and `into_bytes`, whose arrays have the same length as the
+// generated storage.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+fn main() {
+    let mut bitfield = MyFourBytes::new();
+    assert_eq!(bitfield.as_bytes(), &[0; 4]);
+
+    bitfield.set_a(1);
+    bitfield.set_b(5);
+    bitfield.set_d(0x00ab_cdef);
+
+    let bytes: [u8; 4] = *bitfield.as_bytes();
+    let copy = MyFourBytes::from_bytes(bytes);
+    assert_eq!(copy.get_a(), 1);
+    assert_eq!(copy.get_b(), 5);
+    assert_eq!(copy.get_c(), 0);
+    assert_eq!(copy.get_d(), 0x00ab_cdef);
+
+    assert_eq!(copy.into_bytes(), bytes);
+    assert_eq!(bitfield.into_bytes(), bytes);
+}
@@ -0,0 +1,39 @@
+// A #[bitfield] struct is itself a specifier whose width is the total width of
+// its fields, so it can be used as a field of another bitfield. Its accessors
+// then take and return the inner struct, even when it doesn't start on a byte
+// boundary.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Inner {
+    x: B3,
+    y: B5,
+}
+
+#[bitfield]
+pub struct Outer {
+    flag: bool,
+    inner: Inner,
+    rest: B7,
+}
+
+fn main() {
+    assert_eq!(<Inner as Specifier>::BITS, 8);
+    assert_eq!(std::mem::size_of::<Outer>(), 2);
+
+    let mut inner = Inner::new();
+    inner.set_x(5);
+    inner.set_y(19);
+
+    let mut outer = Outer::new();
+    outer.set_flag(true);
+    outer.set_inner(inner);
+    outer.set_rest(100);
+
+    let inner = outer.get_inner();
+    assert_eq!(inner.get_x(), 5);
+    assert_eq!(inner.get_y(), 19);
+    assert_eq!(outer.get_flag(), true);
+    assert_eq!(outer.get_rest(), 100);
+}
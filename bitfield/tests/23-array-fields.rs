@@ -0,0 +1,40 @@
+// An array of specifiers such as `[B3; 4]` is a specifier as wide as all of
+// its elements together. The accessors of an array field take an index and
+// read or write a single element.
+
+use bitfield::*;
+
+#[bitfield(derive(Debug))]
+pub struct Channels {
+    enabled: bool,
+    levels: [B3; 4],
+    reserved: B3,
+}
+
+fn main() {
+    assert_eq!(<[B3; 4] as Specifier>::BITS, 12);
+    assert_eq!(std::mem::size_of::<Channels>(), 2);
+
+    let mut channels = Channels::new();
+    channels.set_enabled(true);
+    channels.set_levels(0, 7);
+    channels.set_levels(2, 5);
+    channels.set_levels(3, 1);
+    channels.set_reserved(6);
+
+    assert_eq!(channels.get_levels(0), 7);
+    assert_eq!(channels.get_levels(1), 0);
+    assert_eq!(channels.get_levels(2), 5);
+    assert_eq!(channels.get_levels(3), 1);
+    assert_eq!(channels.get_levels_checked(2), Ok(5));
+    assert_eq!(channels.get_enabled(), true);
+    assert_eq!(channels.get_reserved(), 6);
+
+    assert_eq!(
+        format!("{:?}", channels),
+        "Channels { enabled: true, levels: [7, 0, 5, 1], reserved: 6 }",
+    );
+
+    let out_of_range = std::panic::catch_unwind(|| Channels::new().get_levels(4));
+    assert!(out_of_range.is_err());
+}
@@ -0,0 +1,28 @@
+// An enum with a power-of-two number of variants, like the four below, is a
+// valid BitfieldSpecifier. Compare with 08-non-power-of-two.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Light {
+    color: Color,
+    brightness: B6,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Color {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+    White = 3,
+}
+
+fn main() {
+    assert_eq!(<Color as Specifier>::BITS, 2);
+
+    let mut light = Light::new();
+    light.set_color(Color::Blue);
+    light.set_brightness(40);
+    assert_eq!(light.get_color(), Color::Blue);
+    assert_eq!(light.get_brightness(), 40);
+}
@@ -0,0 +1,37 @@
+// Fields can start at any bit offset, including ones whose bits span more
+// bytes than their accessor type has. Here `b` is 13 bits wide starting at bit
+// 5, so it spans three bytes but is read and written through a u16.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct UnalignedFields {
+    a: B5,
+    b: B13,
+    c: B6,
+    d: B27,
+    e: B13,
+}
+
+fn main() {
+    let mut bitfield = UnalignedFields::new();
+
+    bitfield.set_a(0b10101);
+    bitfield.set_b(0x1675);
+    bitfield.set_c(0b110011);
+    bitfield.set_d(0x5a5_a5a5);
+    bitfield.set_e(0x1fff);
+    assert_eq!(0b10101, bitfield.get_a());
+    assert_eq!(0x1675, bitfield.get_b());
+    assert_eq!(0b110011, bitfield.get_c());
+    assert_eq!(0x5a5_a5a5, bitfield.get_d());
+    assert_eq!(0x1fff, bitfield.get_e());
+
+    bitfield.set_b(0x0aaa);
+    bitfield.set_d(0);
+    assert_eq!(0b10101, bitfield.get_a());
+    assert_eq!(0x0aaa, bitfield.get_b());
+    assert_eq!(0b110011, bitfield.get_c());
+    assert_eq!(0, bitfield.get_d());
+    assert_eq!(0x1fff, bitfield.get_e());
+}
@@ -0,0 +1,33 @@
+// Setting a field to a value with more bits than the field is wide only stores
+// the low bits of the value. The extra bits must not spill into the fields next
+// to it.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Neighbors {
+    a: B5,
+    b: B3,
+    c: B4,
+    d: B4,
+}
+
+fn main() {
+    let mut bitfield = Neighbors::new();
+    bitfield.set_a(0b10101);
+    bitfield.set_c(0b1001);
+    bitfield.set_d(0b0110);
+
+    bitfield.set_b(200);
+    assert_eq!(0b000, bitfield.get_b());
+    assert_eq!(0b10101, bitfield.get_a());
+    assert_eq!(0b1001, bitfield.get_c());
+    assert_eq!(0b0110, bitfield.get_d());
+
+    bitfield.set_b(0xff);
+    bitfield.set_c(0xf0);
+    assert_eq!(0b111, bitfield.get_b());
+    assert_eq!(0b0000, bitfield.get_c());
+    assert_eq!(0b10101, bitfield.get_a());
+    assert_eq!(0b0110, bitfield.get_d());
+}
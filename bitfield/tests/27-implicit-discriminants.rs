@@ -0,0 +1,46 @@
+// Variants of a BitfieldSpecifier enum don't need explicit discriminants. Like
+// in any Rust enum, a variant without one takes the value after the previous
+// variant, starting from 0.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Entry {
+    mode: Mode,
+    level: Level,
+    reserved: B3,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Level {
+    Zero,
+    Four = 4,
+    Five,
+    Six,
+}
+
+fn main() {
+    assert_eq!(<Mode as Specifier>::BITS, 2);
+    assert_eq!(<Level as Specifier>::BITS, 3);
+
+    let mut entry = Entry::new();
+    assert_eq!(entry.get_mode(), Mode::Off);
+    assert_eq!(entry.get_level(), Level::Zero);
+
+    entry.set_mode(Mode::Medium);
+    entry.set_level(Level::Five);
+    assert_eq!(entry.get_mode(), Mode::Medium);
+    assert_eq!(entry.get_level(), Level::Five);
+
+    entry.set_level(Level::Six);
+    assert_eq!(entry.get_level(), Level::Six);
+    assert_eq!(entry.get_mode(), Mode::Medium);
+}
@@ -0,0 +1,33 @@
+// `#[bitfield_specifier(bits = N)]` gives a BitfieldSpecifier enum a fixed
+// width, which may be wider than its largest discriminant needs. Compare with
+// 29-specifier-bits-too-narrow.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Entry {
+    mode: Mode,
+    enabled: bool,
+    reserved: B7,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bitfield_specifier(bits = 8)]
+pub enum Mode {
+    Off = 0,
+    Standby = 1,
+    Low = 4,
+    High = 5,
+}
+
+fn main() {
+    assert_eq!(<Mode as Specifier>::BITS, 8);
+    assert_eq!(std::mem::size_of::<Entry>(), 2);
+
+    let mut entry = Entry::new();
+    entry.set_mode(Mode::High);
+    entry.set_enabled(true);
+    assert_eq!(entry.get_mode(), Mode::High);
+    assert_eq!(entry.get_enabled(), true);
+    assert_eq!(entry.as_bytes(), &[5, 1]);
+}
@@ -0,0 +1,15 @@
+// A fixed width given with `#[bitfield_specifier(bits = N)]` must be able to
+// hold every discriminant of the enum.
+
+use bitfield::*;
+
+#[derive(BitfieldSpecifier)]
+#[bitfield_specifier(bits = 2)]
+pub enum Mode {
+    Off = 0,
+    Standby = 1,
+    Low = 4,
+    High = 5,
+}
+
+fn main() {}
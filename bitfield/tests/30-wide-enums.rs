@@ -0,0 +1,34 @@
+// An enum whose discriminants don't fit in a byte is serialized using all of
+// the bytes of its discriminant, in little-endian order.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Packet {
+    kind: Kind,
+    flags: B6,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Kind {
+    Ping = 0,
+    Data = 1,
+    Ack = 300,
+    Reset = 1000,
+}
+
+fn main() {
+    assert_eq!(<Kind as Specifier>::BITS, 10);
+    assert_eq!(<Kind as Serialize<2>>::serialize(Kind::Ack), [44, 1]);
+    assert_eq!(<Kind as Serialize<2>>::deserialize([0xe8, 0x03]), Kind::Reset);
+
+    let mut packet = Packet::new();
+    packet.set_kind(Kind::Reset);
+    packet.set_flags(0b101010);
+    assert_eq!(packet.get_kind(), Kind::Reset);
+    assert_eq!(packet.get_flags(), 0b101010);
+
+    packet.set_kind(Kind::Ack);
+    assert_eq!(packet.get_kind(), Kind::Ack);
+    assert_eq!(packet.get_flags(), 0b101010);
+}
@@ -0,0 +1,32 @@
+// Bitfield structs implement Default, which is the same all-zero value as
+// `new()`, so they can be used in structs that derive Default.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+#[derive(Default)]
+pub struct Registers {
+    control: MyFourBytes,
+    status: Option<MyFourBytes>,
+}
+
+fn main() {
+    let default = MyFourBytes::default();
+    let new = MyFourBytes::new();
+    assert_eq!(default.get_a(), new.get_a());
+    assert_eq!(default.get_b(), new.get_b());
+    assert_eq!(default.get_c(), new.get_c());
+    assert_eq!(default.get_d(), new.get_d());
+    assert_eq!(default.as_bytes(), new.as_bytes());
+
+    let registers = Registers::default();
+    assert_eq!(registers.control.as_bytes(), &[0; 4]);
+    assert_eq!(registers.status.unwrap_or_default().as_bytes(), &[0; 4]);
+}
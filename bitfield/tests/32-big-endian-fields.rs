@@ -0,0 +1,32 @@
+// A field marked `#[endian = "big"]` stores its bytes most significant first,
+// as network protocols usually do. Fields are little-endian by default.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Header {
+    version: B8,
+    #[endian = "big"]
+    length: B16,
+    #[endian = "big"]
+    sequence: B24,
+    #[endian = "little"]
+    checksum: B16,
+}
+
+fn main() {
+    let header = Header::from_bytes([0x04, 0x12, 0x34, 0x01, 0x02, 0x03, 0xcd, 0xab]);
+    assert_eq!(header.get_version(), 0x04);
+    assert_eq!(header.get_length(), 0x1234);
+    assert_eq!(header.get_sequence(), 0x010203);
+    assert_eq!(header.get_checksum(), 0xabcd);
+
+    let mut header = Header::new();
+    header.set_version(0x06);
+    header.set_length(0xbeef);
+    header.set_sequence(0x0a0b0c);
+    header.set_checksum(0x1122);
+    assert_eq!(header.as_bytes(), &[0x06, 0xbe, 0xef, 0x0a, 0x0b, 0x0c, 0x22, 0x11]);
+    assert_eq!(header.get_length(), 0xbeef);
+    assert_eq!(header.get_sequence(), 0x0a0b0c);
+}
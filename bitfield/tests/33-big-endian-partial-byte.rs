@@ -0,0 +1,12 @@
+// Only fields that are a whole number of bytes wide can be big-endian.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Header {
+    flags: B4,
+    #[endian = "big"]
+    length: B12,
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+// With `#[bitfield(bit_order = "msb")]` the first field occupies the most
+// significant bits of the first byte, and each field is stored with its most
+// significant bit first, as many hardware registers are documented. The
+// default is `bit_order = "lsb"`, where the first field occupies the least
+// significant bits.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct LsbFirst {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B4,
+    e: B12,
+}
+
+#[bitfield(bit_order = "msb")]
+pub struct MsbFirst {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B4,
+    e: B12,
+}
+
+fn main() {
+    let mut lsb = LsbFirst::new();
+    lsb.set_a(1);
+    lsb.set_b(0b101);
+    lsb.set_c(0b0011);
+    lsb.set_d(0x5);
+    lsb.set_e(0xabc);
+
+    let mut msb = MsbFirst::new();
+    msb.set_a(1);
+    msb.set_b(0b101);
+    msb.set_c(0b0011);
+    msb.set_d(0x5);
+    msb.set_e(0xabc);
+
+    assert_eq!(lsb.as_bytes(), &[0b0011_101_1, 0xc5, 0xab]);
+    assert_eq!(msb.as_bytes(), &[0b1_101_0011, 0x5a, 0xbc]);
+
+    assert_eq!(msb.get_a(), 1);
+    assert_eq!(msb.get_b(), 0b101);
+    assert_eq!(msb.get_c(), 0b0011);
+    assert_eq!(msb.get_d(), 0x5);
+    assert_eq!(msb.get_e(), 0xabc);
+}
@@ -0,0 +1,32 @@
+// Every field also gets a `with_*` method that takes the bitfield by value,
+// sets the field and returns the bitfield, so that a value can be built up in
+// a single expression.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct RedirectionTableEntry {
+    acknowledged: bool,
+    trigger_mode: TriggerMode,
+    levels: [B2; 3],
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum TriggerMode {
+    Edge = 0,
+    Level = 1,
+}
+
+fn main() {
+    let entry = RedirectionTableEntry::new()
+        .with_acknowledged(true)
+        .with_trigger_mode(TriggerMode::Level)
+        .with_levels(0, 3)
+        .with_levels(2, 1);
+
+    assert_eq!(entry.get_acknowledged(), true);
+    assert_eq!(entry.get_trigger_mode(), TriggerMode::Level);
+    assert_eq!(entry.get_levels(0), 3);
+    assert_eq!(entry.get_levels(1), 0);
+    assert_eq!(entry.get_levels(2), 1);
+}
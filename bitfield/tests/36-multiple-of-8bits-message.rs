@@ -0,0 +1,12 @@
+// The error for a bitfield whose size is not a multiple of 8 bits says so in
+// plain words, rather than only naming the marker types used for the check.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct ThreeBitsShort {
+    a: B5,
+    b: B16,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Bitfield structs have associated constants BYTES and BITS giving the size of
+// their storage, so that code serializing them can size its buffers at compile
+// time.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+fn main() {
+    assert_eq!(MyFourBytes::BYTES, 4);
+    assert_eq!(MyFourBytes::BITS, 32);
+
+    let mut buffer = [0u8; MyFourBytes::BYTES];
+    let mut bitfield = MyFourBytes::new();
+    bitfield.set_d(0x00ab_cdef);
+    buffer.copy_from_slice(bitfield.as_bytes());
+    assert_eq!(MyFourBytes::from_bytes(buffer).get_d(), 0x00ab_cdef);
+}
@@ -0,0 +1,14 @@
+// BitfieldSpecifier discriminants are stored as unsigned bits, so a negative
+// discriminant is rejected with an error pointing at it.
+
+use bitfield::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Offset {
+    MinusOne = -1,
+    Zero = 0,
+    One = 1,
+    Two = 2,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// Discriminants are not limited to 32 bits. Here the largest one needs 33 bits,
+// which is the width of the specifier.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Entry {
+    region: Region,
+    flags: B7,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Region {
+    Low = 0,
+    Middle = 0xffff_ffff,
+    High = 0x1_0000_0000,
+    Top = 0x1_8000_0000,
+}
+
+fn main() {
+    assert_eq!(<Region as Specifier>::BITS, 33);
+
+    let mut entry = Entry::new();
+    entry.set_region(Region::High);
+    entry.set_flags(0x7f);
+    assert_eq!(entry.get_region(), Region::High);
+    assert_eq!(entry.get_flags(), 0x7f);
+
+    entry.set_region(Region::Middle);
+    assert_eq!(entry.get_region(), Region::Middle);
+    entry.set_region(Region::Top);
+    assert_eq!(entry.get_region(), Region::Top);
+    assert_eq!(entry.get_flags(), 0x7f);
+}
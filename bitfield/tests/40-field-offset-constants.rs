@@ -0,0 +1,35 @@
+// Each field's bit offset and width are associated constants of the bitfield
+// struct, named after the field, so they can be used in const contexts such as
+// compile-time assertions about the layout.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct RedirectionTableEntry {
+    vector: B8,
+    delivery_mode: B3,
+    levels: [B2; 2],
+    reserved: B1,
+}
+
+const _: () = assert!(RedirectionTableEntry::DELIVERY_MODE_OFFSET == 8);
+const _: () = assert!(RedirectionTableEntry::LEVELS_OFFSET + RedirectionTableEntry::LEVELS_WIDTH == RedirectionTableEntry::RESERVED_OFFSET);
+
+const VECTOR_MASK: u16 = (1 << RedirectionTableEntry::VECTOR_WIDTH) - 1;
+
+fn main() {
+    assert_eq!(RedirectionTableEntry::VECTOR_OFFSET, 0);
+    assert_eq!(RedirectionTableEntry::VECTOR_WIDTH, 8);
+    assert_eq!(RedirectionTableEntry::DELIVERY_MODE_WIDTH, 3);
+    assert_eq!(RedirectionTableEntry::LEVELS_OFFSET, 11);
+    assert_eq!(RedirectionTableEntry::LEVELS_WIDTH, 4);
+    assert_eq!(RedirectionTableEntry::RESERVED_OFFSET, 15);
+    assert_eq!(VECTOR_MASK, 0xff);
+
+    let mut entry = RedirectionTableEntry::new();
+    entry.set_delivery_mode(5);
+    entry.set_levels(1, 3);
+    assert_eq!(entry.get_delivery_mode(), 5);
+    assert_eq!(entry.get_levels(0), 0);
+    assert_eq!(entry.get_levels(1), 3);
+}
@@ -0,0 +1,9 @@
+// An enum without variants has no values to store, so deriving
+// BitfieldSpecifier for it is an error rather than a panic in the macro.
+
+use bitfield::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Never {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+// A #[bitfield] struct without fields would have no storage at all, so it is
+// an error.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Nothing {}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// The smallest bitfields, with a single field or a single-variant enum, work
+// like any other.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct OneByte {
+    value: B8,
+}
+
+#[bitfield]
+pub struct Tagged {
+    tag: Tag,
+    value: B8,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Tag {
+    Only,
+}
+
+fn main() {
+    let mut bitfield = OneByte::new();
+    bitfield.set_value(0xa5);
+    assert_eq!(bitfield.get_value(), 0xa5);
+    assert_eq!(OneByte::BYTES, 1);
+
+    assert_eq!(<Tag as Specifier>::BITS, 0);
+    let mut tagged = Tagged::new();
+    tagged.set_tag(Tag::Only);
+    tagged.set_value(7);
+    assert_eq!(tagged.get_tag(), Tag::Only);
+    assert_eq!(tagged.get_value(), 7);
+}
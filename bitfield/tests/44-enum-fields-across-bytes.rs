@@ -0,0 +1,43 @@
+// Enum fields are read and written through the enum's Serialize impl, like any
+// other specifier, so they work at any position, including ones that cross a
+// byte boundary.
+
+use bitfield::*;
+
+#[bitfield(derive(Debug))]
+pub struct Instruction {
+    condition: B6,
+    opcode: Opcode,
+    operand: B7,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Opcode {
+    Load = 0b000,
+    Store = 0b001,
+    Add = 0b010,
+    Sub = 0b011,
+    And = 0b100,
+    Or = 0b101,
+    Jump = 0b110,
+    Halt = 0b111,
+}
+
+fn main() {
+    let mut instruction = Instruction::new()
+        .with_condition(0b111111)
+        .with_operand(0b1111111);
+    assert_eq!(instruction.get_opcode(), Opcode::Load);
+
+    instruction.set_opcode(Opcode::Jump);
+    assert_eq!(instruction.get_opcode(), Opcode::Jump);
+    assert_eq!(instruction.get_opcode_checked(), Ok(Opcode::Jump));
+    assert_eq!(instruction.get_condition(), 0b111111);
+    assert_eq!(instruction.get_operand(), 0b1111111);
+
+    instruction.set_opcode(Opcode::Store);
+    assert_eq!(
+        format!("{:?}", instruction),
+        "Instruction { condition: 63, opcode: Store, operand: 127 }",
+    );
+}
@@ -0,0 +1,26 @@
+// `#[bitfield(derive(PartialEq, Eq))]` compares bitfields by their storage
+// bytes, so two bitfields are equal exactly when all their fields are.
+
+use bitfield::*;
+
+#[bitfield(derive(PartialEq, Eq, Debug))]
+pub struct MyFourBytes {
+    a: B1,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+fn assert_eq_trait<T: Eq>() {}
+
+fn main() {
+    assert_eq_trait::<MyFourBytes>();
+
+    let first = MyFourBytes::new().with_a(1).with_d(0x00ab_cdef);
+    let second = MyFourBytes::new().with_d(0x00ab_cdef).with_a(1);
+    assert_eq!(first, second);
+
+    let third = MyFourBytes::new().with_a(1).with_d(0x00ab_cdee);
+    assert_ne!(first, third);
+    assert_ne!(first, MyFourBytes::new());
+}
@@ -0,0 +1,59 @@
+// Discriminants can be written in any integer literal form. The width of the
+// specifier only depends on their values: 0xff needs 8 bits no matter how it
+// is written.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Entry {
+    hex: Hex,
+    binary: Binary,
+    mixed: Mixed,
+    reserved: B1,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Hex {
+    Zero = 0x00,
+    Low = 0x0F,
+    High = 0xF0,
+    Full = 0xFF,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Binary {
+    A = 0b0000,
+    B = 0b0011,
+    C = 0b0110,
+    D = 0b1000,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mixed {
+    Decimal = 1,
+    Octal = 0o7,
+    Underscored = 0b0_1_0,
+    Suffixed = 3_isize,
+}
+
+fn main() {
+    assert_eq!(<Hex as Specifier>::BITS, 8);
+    assert_eq!(<Binary as Specifier>::BITS, 4);
+    assert_eq!(<Mixed as Specifier>::BITS, 3);
+
+    let mut entry = Entry::new();
+    entry.set_hex(Hex::High);
+    entry.set_binary(Binary::D);
+    entry.set_mixed(Mixed::Octal);
+    entry.set_reserved(1);
+    assert_eq!(entry.get_hex(), Hex::High);
+    assert_eq!(entry.get_binary(), Binary::D);
+    assert_eq!(entry.get_mixed(), Mixed::Octal);
+    assert_eq!(entry.get_reserved(), 1);
+
+    entry.set_mixed(Mixed::Suffixed);
+    entry.set_hex(Hex::Full);
+    assert_eq!(entry.get_mixed(), Mixed::Suffixed);
+    assert_eq!(entry.get_hex(), Hex::Full);
+    assert_eq!(entry.get_binary(), Binary::D);
+}
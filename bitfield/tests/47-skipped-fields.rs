@@ -0,0 +1,46 @@
+// Fields marked #[skip] are kept as ordinary struct fields next to the packed
+// data. They take no part in the bit layout and get no accessors, and they
+// start out as their Default value.
+
+use bitfield::*;
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq)]
+pub struct Control;
+
+#[bitfield(derive(Debug, PartialEq))]
+pub struct Register {
+    enabled: bool,
+    mode: B7,
+    #[skip]
+    marker: PhantomData<Control>,
+    #[skip]
+    pub writes: u32,
+}
+
+fn main() {
+    assert_eq!(Register::BITS, 8);
+    assert_eq!(Register::BYTES, 1);
+
+    let mut register = Register::new();
+    assert_eq!(register.writes, 0);
+    let _: PhantomData<Control> = register.marker;
+
+    register.set_enabled(true);
+    register.set_mode(0b101_0101);
+    register.writes += 1;
+    assert!(register.get_enabled());
+    assert_eq!(register.get_mode(), 0b101_0101);
+    assert_eq!(register.writes, 1);
+    assert_eq!(register.as_bytes(), &[0b1010_1011]);
+
+    let copy = Register::from_bytes([0b1010_1011]);
+    assert_eq!(copy.writes, 0);
+    assert_ne!(copy, register);
+    register.writes = 0;
+    assert_eq!(copy, register);
+
+    let debug = format!("{:?}", register);
+    assert!(debug.starts_with("Register { enabled: true, mode: 85, marker: PhantomData<"));
+    assert!(debug.ends_with("Control>, writes: 0 }"));
+}
@@ -0,0 +1,30 @@
+// Attributes written on the struct are kept on the generated struct, in their
+// original order and before its visibility. The doc comment is checked with
+// `missing_docs`, which is only denied for the struct and its fields, and `repr(C)`
+// with the offset of a skipped field, which must follow the data bytes.
+
+use bitfield::*;
+
+/// A status register.
+#[bitfield]
+#[repr(C, align(4))]
+#[deny(missing_docs)]
+#[derive(Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+    #[skip]
+    /// How many times the register has been read.
+    pub reads: u16,
+}
+
+fn main() {
+    assert_eq!(std::mem::align_of::<Status>(), 4);
+    assert_eq!(std::mem::offset_of!(Status, reads), Status::BYTES + 1);
+
+    let status = Status::new().with_ready(true).with_code(0x42);
+    let copy = status;
+    assert!(copy.get_ready());
+    assert_eq!(copy.get_code(), 0x42);
+    assert_eq!(status.as_bytes(), copy.as_bytes());
+}
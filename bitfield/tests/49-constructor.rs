@@ -0,0 +1,58 @@
+// With `#[bitfield(ctor)]` the struct also gets a `new_with` constructor,
+// which takes the value of every field in declaration order and sets them all
+// at once. Array fields take an array of element values. Fields may share their
+// names with the locals of the generated constructor.
+
+use bitfield::*;
+
+#[bitfield(ctor)]
+pub struct Packet {
+    version: B3,
+    urgent: bool,
+    kind: Kind,
+    flags: [B2; 3],
+    length: B12,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Kind {
+    Data,
+    Ack,
+    Reset,
+    Ping,
+}
+
+#[bitfield(ctor)]
+pub struct Locals {
+    bitfield: B4,
+    index: [B1; 2],
+    val: B2,
+}
+
+fn main() {
+    let locals = Locals::new_with(9, [1, 0], 2);
+    assert_eq!(locals.get_bitfield(), 9);
+    assert_eq!(locals.get_index(0), 1);
+    assert_eq!(locals.get_index(1), 0);
+    assert_eq!(locals.get_val(), 2);
+
+    let packet = Packet::new_with(5, true, Kind::Reset, [1, 2, 3], 0xABC);
+
+    assert_eq!(packet.get_version(), 5);
+    assert!(packet.get_urgent());
+    assert_eq!(packet.get_kind(), Kind::Reset);
+    assert_eq!(packet.get_flags(0), 1);
+    assert_eq!(packet.get_flags(1), 2);
+    assert_eq!(packet.get_flags(2), 3);
+    assert_eq!(packet.get_length(), 0xABC);
+
+    let same = Packet::new()
+        .with_version(5)
+        .with_urgent(true)
+        .with_kind(Kind::Reset)
+        .with_flags(0, 1)
+        .with_flags(1, 2)
+        .with_flags(2, 3)
+        .with_length(0xABC);
+    assert_eq!(packet.as_bytes(), same.as_bytes());
+}
@@ -0,0 +1,46 @@
+// A `window` option reads and writes a run of adjacent fields as a single
+// integer, with the first field in the least significant bits. Here `flags`
+// covers `ready`, `error` and `mode`, and `header` the whole first two bytes.
+
+use bitfield::*;
+
+#[bitfield(
+    window(name = "flags", from = ready, to = mode),
+    window(name = "header", from = id, to = mode),
+)]
+pub struct Status {
+    id: B5,
+    ready: bool,
+    error: bool,
+    mode: B4,
+    reserved: B5,
+}
+
+fn main() {
+    let mut status = Status::new();
+    status.set_id(0b10110);
+    status.set_ready(true);
+    status.set_error(false);
+    status.set_mode(0b1001);
+    status.set_reserved(0b11111);
+
+    let concatenated = status.get_ready() as u64
+        | (status.get_error() as u64) << 1
+        | (status.get_mode() as u64) << 2;
+    assert_eq!(status.get_flags(), concatenated);
+    assert_eq!(status.get_flags(), 0b100101);
+    assert_eq!(status.get_header(), concatenated << 5 | status.get_id() as u64);
+
+    status.set_flags(0b011010);
+    assert!(!status.get_ready());
+    assert!(status.get_error());
+    assert_eq!(status.get_mode(), 0b0110);
+    assert_eq!(status.get_id(), 0b10110);
+    assert_eq!(status.get_reserved(), 0b11111);
+
+    // Bits beyond the width of the window are dropped
+    status.set_flags(u64::MAX);
+    assert_eq!(status.get_flags(), 0b111111);
+    assert_eq!(status.get_id(), 0b10110);
+    assert_eq!(status.get_reserved(), 0b11111);
+}
@@ -0,0 +1,12 @@
+// The fields that bound a window must exist in the struct, and the error
+// points at the name that does not.
+
+use bitfield::*;
+
+#[bitfield(window(name = "flags", from = ready, to = modes))]
+pub struct Status {
+    ready: bool,
+    mode: B7,
+}
+
+fn main() {}
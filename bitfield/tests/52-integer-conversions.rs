@@ -0,0 +1,37 @@
+// A BitfieldSpecifier enum converts to and from the narrowest unsigned integer
+// that holds its bits, so that it can be used outside of a bitfield too.
+// Converting from an integer that is not a discriminant is an error rather
+// than a panic.
+
+use bitfield::*;
+use std::convert::TryFrom;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Wide {
+    Small = 0,
+    Large = 0x1234,
+}
+
+fn main() {
+    assert_eq!(u8::from(Direction::South), 2);
+    let raw: u8 = Direction::West.into();
+    assert_eq!(raw, 3);
+
+    assert_eq!(Direction::try_from(1u8), Ok(Direction::East));
+    let error = Direction::try_from(4u8).unwrap_err();
+    assert_eq!(error.value(), 4);
+
+    // 0x1234 takes 13 bits, so the integer type is a u16
+    assert_eq!(u16::from(Wide::Large), 0x1234);
+    assert_eq!(Wide::try_from(0x1234u16), Ok(Wide::Large));
+    assert_eq!(Wide::try_from(0u16), Ok(Wide::Small));
+    assert_eq!(Wide::try_from(1u16).unwrap_err().value(), 1);
+}
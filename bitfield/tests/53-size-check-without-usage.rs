@@ -0,0 +1,13 @@
+// The total size check is a top-level item of its own, so it fires for a
+// bitfield that nothing ever constructs or reads. These fields add up to 12
+// bits.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Unused {
+    low: B4,
+    high: B8,
+}
+
+fn main() {}
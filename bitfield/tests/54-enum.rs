@@ -0,0 +1,12 @@
+// #[bitfield] on anything other than a struct with named fields is an error at
+// the item, rather than an item that silently has no accessors.
+
+use bitfield::*;
+
+#[bitfield]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// A bitfield with #[skip] fields can't be nested inside another bitfield. The
+// skipped fields aren't part of its bytes, so it can't be packed like the
+// other fields, and the error says so at the field that tries.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Inner {
+    flag: bool,
+    value: B7,
+    #[skip]
+    reads: u32,
+}
+
+#[bitfield]
+pub struct Outer {
+    inner: Inner,
+    rest: B8,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// The generated checked getters and integer conversions spell out
+// `::core::result::Result`, so they still compile in a module that defines
+// its own one-parameter `Result` alias, which is a common pattern.
+
+mod device {
+    use bitfield::*;
+    use std::convert::TryFrom;
+
+    #[derive(Debug)]
+    pub struct DeviceError;
+
+    pub type Result<T> = std::result::Result<T, DeviceError>;
+
+    #[derive(BitfieldSpecifier, Debug, PartialEq)]
+    pub enum Mode {
+        Off = 0,
+        On = 1,
+    }
+
+    #[bitfield]
+    pub struct Control {
+        pub mode: Mode,
+        pub level: B7,
+    }
+
+    pub fn mode(control: &Control) -> Result<Mode> {
+        control.get_mode_checked().map_err(|_| DeviceError)
+    }
+
+    pub fn mode_from_bits(bits: u8) -> Result<Mode> {
+        Mode::try_from(bits).map_err(|_| DeviceError)
+    }
+}
+
+use device::{Control, Mode};
+
+fn main() {
+    let mut control = Control::new();
+    control.set_mode(Mode::On);
+    control.set_level(100);
+    assert_eq!(device::mode(&control).unwrap(), Mode::On);
+    assert_eq!(control.get_level_checked(), Ok(100));
+
+    assert_eq!(device::mode_from_bits(0).unwrap(), Mode::Off);
+    assert!(device::mode_from_bits(2).is_err());
+    assert_eq!(u8::from(Mode::On), 1);
+}
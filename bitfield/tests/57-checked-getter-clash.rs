@@ -0,0 +1,13 @@
+// Every field gets a `get_*_checked` getter, so a field named after another
+// field with a `_checked` suffix would define the same method twice. The
+// error points at the field whose plain getter clashes.
+
+use bitfield::*;
+
+#[bitfield]
+pub struct Sample {
+    value: B4,
+    value_checked: B4,
+}
+
+fn main() {}
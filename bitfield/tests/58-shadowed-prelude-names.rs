@@ -0,0 +1,44 @@
+// The generated impls name standard traits and types by their full paths, so
+// they are not affected by items of the same name in the struct's module.
+
+mod shadowing {
+    use bitfield::*;
+
+    #[allow(dead_code)]
+    pub trait PartialEq {}
+
+    #[allow(dead_code)]
+    pub trait Eq {}
+
+    #[allow(dead_code)]
+    pub trait Default {}
+
+    #[allow(dead_code)]
+    pub struct Vec;
+
+    #[bitfield(derive(PartialEq, Eq, Debug))]
+    pub struct Flags {
+        pub a: B4,
+        pub b: [B2; 2],
+        #[skip]
+        pub label: &'static str,
+    }
+}
+
+use shadowing::Flags;
+
+fn assert_eq_trait<T: Eq>() {}
+
+fn main() {
+    assert_eq_trait::<Flags>();
+    assert!(Flags::new().with_a(3) == Flags::new().with_a(3));
+    assert!(Flags::new().with_a(3) != Flags::new().with_b(1, 3));
+
+    let flags = Flags::default().with_a(3).with_b(0, 1);
+    assert_eq!(flags.label, "");
+    assert_eq!(
+        format!("{:?}", flags),
+        "Flags { a: 3, b: [1, 0], label: \"\" }",
+    );
+    assert_eq!(Flags::from_bytes([0x13]).label, "");
+}
@@ -0,0 +1,1087 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute,
+    DeriveInput,
+    Data,
+    Expr,
+    ext::IdentExt,
+    Field,
+    Fields,
+    Ident,
+    LitStr,
+    MacroDelimiter,
+    Meta,
+    MetaList,
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    parenthesized,
+    parse_macro_input,
+    parse_quote,
+    punctuated::Punctuated,
+    PathArguments,
+    spanned::Spanned,
+    Token,
+    Type,
+    TypePath,
+};
+
+// The module that each recognized standard library type is defined in, so that fully-qualified
+// paths such as `std::option::Option<T>` are recognized as well as the bare type name.
+fn std_module_name(outer_type_name: &'static str) -> &'static str {
+    match outer_type_name {
+        "Option" => "option",
+        "Vec" => "vec",
+        "Box" => "boxed",
+        _ => "collections",
+    }
+}
+
+fn is_type_path(path: &syn::Path, outer_type_name: &'static str) -> bool {
+    let leading_segments_without_arguments = path.segments
+        .iter()
+        .rev()
+        .skip(1)
+        .all(|segment| segment.arguments.is_none());
+
+    let idents: Vec<String> = path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+
+    let idents: Vec<&str> = idents.iter().map(String::as_str).collect();
+
+    leading_segments_without_arguments && match idents.as_slice() {
+        [type_name] => path.leading_colon.is_none() && *type_name == outer_type_name,
+        [crate_name, module_name, type_name] => {
+            ["std", "core", "alloc"].contains(crate_name)
+                && *module_name == std_module_name(outer_type_name)
+                && *type_name == outer_type_name
+        },
+        _ => false,
+    }
+}
+
+fn generic_args<'a>(ty: &'a Type, outer_type_name: &'static str) -> Option<Vec<&'a Type>> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) if is_type_path(path, outer_type_name) => {
+            match &path.segments.last()?.arguments {
+                PathArguments::AngleBracketed(generic_args) => {
+                    generic_args.args
+                        .iter()
+                        .map(|arg| {
+                            match arg {
+                                syn::GenericArgument::Type(inner_type) => Some(inner_type),
+                                _ => None,
+                            }
+                        })
+                        .collect()
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+// Renders a type roughly the way it would be written in source, for use in error messages.
+fn type_to_string(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" < ", "<")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+fn inner_type<'a>(ty: &'a Type, outer_type_name: &'static str) -> Option<&'a Type> {
+    match generic_args(ty, outer_type_name)?.as_slice() {
+        [inner_type] => Some(inner_type),
+        _ => None,
+    }
+}
+
+// The collection types that can be filled one item at a time through a `builder(each = "...")`
+// setter.
+enum EachCollection<'a> {
+    Vec(&'a Type),
+    HashSet(&'a Type),
+    HashMap(&'a Type, &'a Type),
+}
+
+impl<'a> EachCollection<'a> {
+    fn from_type(ty: &'a Type) -> Option<Self> {
+        if let Some(item_type) = inner_type(ty, "Vec") {
+            return Some(EachCollection::Vec(item_type));
+        }
+
+        // Sets and maps may carry an extra type argument for the hasher.
+        if let Some(args) = generic_args(ty, "HashSet") {
+            return match args.as_slice() {
+                [item_type] | [item_type, _] => Some(EachCollection::HashSet(item_type)),
+                _ => None,
+            };
+        }
+
+        if let Some(args) = generic_args(ty, "HashMap") {
+            return match args.as_slice() {
+                [key_type, value_type] | [key_type, value_type, _] => Some(EachCollection::HashMap(key_type, value_type)),
+                _ => None,
+            };
+        }
+
+        None
+    }
+
+    fn setter_params(&self) -> proc_macro2::TokenStream {
+        match self {
+            EachCollection::Vec(item_type) | EachCollection::HashSet(item_type) => quote! { item: #item_type },
+            EachCollection::HashMap(key_type, value_type) => quote! { key: #key_type, value: #value_type },
+        }
+    }
+
+    fn setter_doc(&self, field_name: &Ident) -> String {
+        match self {
+            EachCollection::Vec(_) => format!("Appends a single item to the `{}` field.", field_name.unraw()),
+            EachCollection::HashSet(_) => format!("Inserts a single item into the `{}` field.", field_name.unraw()),
+            EachCollection::HashMap(_, _) => format!("Inserts a single key-value pair into the `{}` field.", field_name.unraw()),
+        }
+    }
+
+    fn insert_call(&self) -> proc_macro2::TokenStream {
+        match self {
+            EachCollection::Vec(_) => quote! { push(item) },
+            EachCollection::HashSet(_) => quote! { insert(item) },
+            EachCollection::HashMap(_, _) => quote! { insert(key, value) },
+        }
+    }
+}
+
+fn parse_lit_str_value(input: ParseStream) -> syn::Result<LitStr> {
+    let _: Token![=] = input.parse()?;
+
+    input.parse()
+}
+
+// The contents of a nested setter option such as:
+// #[builder(setter(into))]
+// #[builder(setter(boxed))]
+// #[builder(setter(into, boxed))]
+#[derive(Default)]
+struct SetterInfo {
+    into: bool,
+    boxed: bool,
+}
+
+impl Parse for SetterInfo {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut setter_info = SetterInfo::default();
+
+        loop {
+            let key: Ident = input.parse()?;
+
+            if key == "into" && !setter_info.into {
+                setter_info.into = true;
+            } else if key == "boxed" && !setter_info.boxed {
+                setter_info.boxed = true;
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'into' or 'boxed'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(setter_info)
+    }
+}
+
+fn parse_setter_info(input: ParseStream) -> syn::Result<SetterInfo> {
+    let content;
+    parenthesized!(content in input);
+
+    content.parse()
+}
+
+// The contents of a field attribute such as:
+// #[builder(each = "arg")]
+// #[builder(each = "arg", keep_bulk)]
+// #[builder(default = "Vec::new()")]
+// #[builder(setter(into))]
+// #[builder(setter(boxed))]
+// #[builder(skip)]
+#[derive(Default)]
+struct FieldBuilderInfo {
+    each_name: Option<LitStr>,
+    keep_bulk: bool,
+    default: Option<LitStr>,
+    setter: Option<SetterInfo>,
+    skip: bool,
+}
+
+impl Parse for FieldBuilderInfo {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut builder_info = FieldBuilderInfo::default();
+
+        loop {
+            let key: Ident = input.parse()?;
+
+            if key == "each" && builder_info.each_name.is_none() {
+                builder_info.each_name = Some(parse_lit_str_value(input)?);
+            } else if key == "keep_bulk" && !builder_info.keep_bulk {
+                builder_info.keep_bulk = true;
+            } else if key == "default" && builder_info.default.is_none() {
+                builder_info.default = Some(parse_lit_str_value(input)?);
+            } else if key == "setter" && builder_info.setter.is_none() {
+                builder_info.setter = Some(parse_setter_info(input)?);
+            } else if key == "skip" && !builder_info.skip {
+                builder_info.skip = true;
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'each', 'keep_bulk', 'default', 'setter' or 'skip'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(builder_info)
+    }
+}
+
+// The validated form of FieldBuilderInfo, with the default value parsed into an expression.
+#[derive(Default)]
+struct FieldOptions {
+    // The `builder(...)` attribute these options came from, for reporting errors.
+    attr_meta: Option<Meta>,
+    each_name: Option<LitStr>,
+    keep_bulk: bool,
+    default: Option<Expr>,
+    setter_into: bool,
+    setter_boxed: bool,
+    skip: bool,
+}
+
+fn field_options_from_attr(attr: &Attribute) -> syn::Result<Option<FieldOptions>> {
+    match &attr.meta {
+        syn::Meta::List(MetaList { path, delimiter: MacroDelimiter::Paren(_), tokens, .. }) if path.is_ident("builder") => {
+            let builder_info = match syn::parse2::<FieldBuilderInfo>(tokens.clone()) {
+                Ok(builder_info) => builder_info,
+                Err(_) => return Err(syn::Error::new_spanned(&attr.meta, "expected `builder(each = \"...\")`")),
+            };
+
+            if builder_info.each_name.is_some() && builder_info.default.is_some() {
+                return Err(syn::Error::new_spanned(&attr.meta, "`each` and `default` cannot be used together; fields with `each` already default to an empty collection"));
+            }
+
+            if let Some(each_name) = &builder_info.each_name {
+                if !is_valid_each_name(each_name) {
+                    return Err(syn::Error::new_spanned(each_name, "expected `each` to be a valid identifier"));
+                }
+            }
+
+            if builder_info.each_name.is_none() && builder_info.keep_bulk {
+                return Err(syn::Error::new_spanned(&attr.meta, "`keep_bulk` can only be used together with `each`"));
+            }
+
+            let setter_info = builder_info.setter.unwrap_or_default();
+
+            if builder_info.skip && (setter_info.into || setter_info.boxed) {
+                return Err(syn::Error::new_spanned(&attr.meta, "`setter` and `skip` cannot be used together; skipped fields have no setters"));
+            }
+
+            if setter_info.boxed && builder_info.each_name.is_some() {
+                return Err(syn::Error::new_spanned(&attr.meta, "`setter(boxed)` cannot be used together with `each`"));
+            }
+
+            if builder_info.each_name.is_some() && builder_info.skip {
+                return Err(syn::Error::new_spanned(&attr.meta, "`each` and `skip` cannot be used together; skipped fields have no setters"));
+            }
+
+            let default = match builder_info.default {
+                Some(default) => match default.parse::<Expr>() {
+                    Ok(default) => Some(default),
+                    Err(_) => return Err(syn::Error::new_spanned(&default, "expected `default` to be a valid Rust expression")),
+                },
+                None => None,
+            };
+
+            Ok(Some(FieldOptions {
+                attr_meta: Some(attr.meta.clone()),
+                each_name: builder_info.each_name,
+                keep_bulk: builder_info.keep_bulk,
+                default,
+                setter_into: setter_info.into,
+                setter_boxed: setter_info.boxed,
+                skip: builder_info.skip,
+            }))
+        },
+        _ => Ok(None),
+    }
+}
+
+fn field_options(attrs: &Vec<Attribute>) -> syn::Result<FieldOptions> {
+    let mut unique_field_options = None;
+
+    for attr in attrs {
+        let field_options = field_options_from_attr(attr)?;
+        if field_options.is_some() {
+            if unique_field_options.is_some() {
+                return Err(syn::Error::new_spanned(&attr.meta, "expected only one `builder` attribute"));
+            }
+            unique_field_options = field_options;
+        }
+    }
+
+    Ok(unique_field_options.unwrap_or_default())
+}
+
+// The contents of a struct attribute such as:
+// #[builder(setter(into))]
+// #[builder(name = "CommandFactory")]
+// #[builder(error = "ConfigError")]
+// #[builder(getters, clearers)]
+// #[builder(requires_exactly_one(file, url))]
+// #[builder(owned)]
+// #[builder(builder_fn = "make_builder", build_fn = "finish")]
+// #[builder(validate = "Self::check")]
+#[derive(Default)]
+struct StructOptions {
+    setter_into: bool,
+    builder_name: Option<Ident>,
+    error_type: Option<Type>,
+    getters: bool,
+    clearers: bool,
+    exactly_one_groups: Vec<Vec<Ident>>,
+    owned: bool,
+    builder_fn_name: Option<Ident>,
+    build_fn_name: Option<Ident>,
+    validate: Option<syn::Path>,
+}
+
+// Parses the value of a `key = "..."` pair that names a generated item.
+fn parse_ident_value(input: ParseStream, key: &Ident) -> syn::Result<Ident> {
+    let value = parse_lit_str_value(input)?;
+    match value.parse::<Ident>() {
+        Ok(ident) => Ok(ident),
+        Err(_) => Err(syn::Error::new_spanned(&value, format!("expected `{}` to be a valid identifier", key))),
+    }
+}
+
+impl Parse for StructOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut struct_options = StructOptions::default();
+
+        loop {
+            let key: Ident = input.parse()?;
+
+            if key == "setter" && !struct_options.setter_into {
+                let setter_info = parse_setter_info(input)?;
+                if setter_info.boxed {
+                    return Err(syn::Error::new(key.span(), "`setter(boxed)` can only be used on fields"));
+                }
+
+                struct_options.setter_into = setter_info.into;
+            } else if key == "name" && struct_options.builder_name.is_none() {
+                struct_options.builder_name = Some(parse_ident_value(input, &key)?);
+            } else if key == "error" && struct_options.error_type.is_none() {
+                let error_type = parse_lit_str_value(input)?;
+                match error_type.parse::<Type>() {
+                    Ok(error_type) => struct_options.error_type = Some(error_type),
+                    Err(_) => return Err(syn::Error::new_spanned(&error_type, "expected `error` to be a valid type")),
+                }
+            } else if key == "getters" && !struct_options.getters {
+                struct_options.getters = true;
+            } else if key == "clearers" && !struct_options.clearers {
+                struct_options.clearers = true;
+            } else if key == "requires_exactly_one" {
+                let content;
+                parenthesized!(content in input);
+
+                let field_names = Punctuated::<Ident, Token![,]>::parse_separated_nonempty(&content)?;
+                if !content.is_empty() {
+                    return Err(content.error("expected a comma-separated list of field names"));
+                }
+
+                struct_options.exactly_one_groups.push(field_names.into_iter().collect());
+            } else if key == "owned" && !struct_options.owned {
+                struct_options.owned = true;
+            } else if key == "builder_fn" && struct_options.builder_fn_name.is_none() {
+                struct_options.builder_fn_name = Some(parse_ident_value(input, &key)?);
+            } else if key == "build_fn" && struct_options.build_fn_name.is_none() {
+                struct_options.build_fn_name = Some(parse_ident_value(input, &key)?);
+            } else if key == "validate" && struct_options.validate.is_none() {
+                let validate = parse_lit_str_value(input)?;
+                match validate.parse::<syn::Path>() {
+                    Ok(validate) => struct_options.validate = Some(validate),
+                    Err(_) => return Err(syn::Error::new_spanned(&validate, "expected `validate` to be the path of a function")),
+                }
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'setter', 'name', 'error', 'getters', 'clearers', 'requires_exactly_one', 'owned', 'builder_fn', 'build_fn' or 'validate'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(struct_options)
+    }
+}
+
+fn struct_options(attrs: &Vec<Attribute>) -> syn::Result<StructOptions> {
+    let mut unique_struct_options = None;
+
+    for attr in attrs {
+        // Unlike field attributes, we may see attributes here that are unrelated to this macro
+        if let syn::Meta::List(MetaList { path, delimiter: MacroDelimiter::Paren(_), tokens, .. }) = &attr.meta {
+            if path.is_ident("builder") {
+                if unique_struct_options.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "expected only one `builder` attribute"));
+                }
+                unique_struct_options = Some(syn::parse2::<StructOptions>(tokens.clone())?);
+            }
+        }
+    }
+
+    Ok(unique_struct_options.unwrap_or_default())
+}
+
+// Whether an `each` name can become a method name, either as it is or as a raw identifier.
+fn is_valid_each_name(each_name: &LitStr) -> bool {
+    let each_name_value = each_name.value();
+    syn::parse_str::<Ident>(&each_name_value).is_ok() || syn::parse_str::<Ident>(&format!("r#{}", each_name_value)).is_ok()
+}
+
+// Creates the name of an `each` setter. A name that is a keyword, such as "type", becomes a raw
+// identifier so that it still produces a valid method.
+fn each_setter_ident(each_name: &LitStr) -> Ident {
+    let each_name_value = each_name.value();
+    match syn::parse_str::<Ident>(&each_name_value) {
+        Ok(mut each_ident) => {
+            each_ident.set_span(each_name.span());
+            each_ident
+        },
+        Err(_) => Ident::new_raw(&each_name_value, each_name.span()),
+    }
+}
+
+// Whether a field gets the setter that takes its whole value, named after the field itself.
+//
+// A field with an `each` setter keeps this setter when `keep_bulk` is given. Otherwise it is
+// dropped only when the `each` setter has the same name as the field, since the two could not
+// both be generated.
+fn has_all_at_once_setter(field: &Field, field_options: &FieldOptions) -> bool {
+    match (&field.ident, &field_options.each_name) {
+        (_, _) if field_options.skip => false,
+        (_, Some(_)) if field_options.keep_bulk => true,
+        (Some(field_name), Some(each_name)) => field_name.unraw() != each_setter_ident(each_name).unraw(),
+        _ => true,
+    }
+}
+
+// Reports `each` setters that would be generated with the same name as another setter, which
+// would otherwise surface as a confusing duplicate definition error in the generated code.
+fn check_each_name_collisions(fields_with_options: &[(Field, FieldOptions)]) -> syn::Result<()> {
+    let mut error: Option<syn::Error> = None;
+
+    for (index, (field, field_options)) in fields_with_options.iter().enumerate() {
+        let each_name = match &field_options.each_name {
+            Some(each_name) => each_name,
+            None => continue,
+        };
+
+        let each_ident = each_setter_ident(each_name).unraw();
+
+        if field_options.keep_bulk && field.ident.as_ref().is_some_and(|field_name| field_name.unraw() == each_ident) {
+            let this_error = syn::Error::new_spanned(each_name, "`keep_bulk` requires the `each` setter to be named differently from the field");
+            match &mut error {
+                Some(error) => error.combine(this_error),
+                None => error = Some(this_error),
+            }
+        }
+
+        for (other_index, (other_field, other_field_options)) in fields_with_options.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+
+            let message = match &other_field.ident {
+                Some(other_field_name) if other_field_name.unraw() == each_ident && has_all_at_once_setter(other_field, other_field_options) => {
+                    format!("the `each` setter `{}` conflicts with the setter for field `{}`", each_ident, other_field_name.unraw())
+                },
+                Some(other_field_name) if other_index < index && other_field_options.each_name.as_ref().is_some_and(|other_each_name| each_setter_ident(other_each_name).unraw() == each_ident) => {
+                    format!("the `each` setter `{}` conflicts with the `each` setter for field `{}`", each_ident, other_field_name.unraw())
+                },
+                _ => continue,
+            };
+
+            let this_error = syn::Error::new_spanned(each_name, message);
+            match &mut error {
+                Some(error) => error.combine(this_error),
+                None => error = Some(this_error),
+            }
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+// Generates the `build` checks for each `requires_exactly_one(...)` group. Every name in a group
+// has to refer to an `Option` field that has a setter, since that is the only kind of field that
+// can legitimately be left unset.
+fn exactly_one_checks(exactly_one_groups: &[Vec<Ident>], fields_with_options: &[(Field, FieldOptions)]) -> syn::Result<proc_macro2::TokenStream> {
+    let mut checks = Vec::with_capacity(exactly_one_groups.len());
+
+    for exactly_one_group in exactly_one_groups {
+        for group_field_name in exactly_one_group {
+            let group_field = fields_with_options
+                .iter()
+                .find(|(field, _)| field.ident.as_ref().is_some_and(|field_name| field_name.unraw() == group_field_name.unraw()));
+
+            match group_field {
+                Some((field, field_options)) if inner_type(&field.ty, "Option").is_some() && !field_options.skip => {},
+                Some(_) => return Err(syn::Error::new(group_field_name.span(), format!("`requires_exactly_one` only accepts `Option` fields, but `{}` is not one", group_field_name.unraw()))),
+                None => return Err(syn::Error::new(group_field_name.span(), format!("no field named `{}`", group_field_name.unraw()))),
+            }
+        }
+
+        let group_field_name_strings: Vec<String> = exactly_one_group
+            .iter()
+            .map(|group_field_name| group_field_name.unraw().to_string())
+            .collect();
+
+        let set_count = Ident::new("set_count", Span::mixed_site());
+
+        checks.push(
+            quote! {
+                let #set_count = [#(self.#exactly_one_group.is_some()),*]
+                    .iter()
+                    .filter(|is_set| **is_set)
+                    .count();
+
+                if #set_count != 1 {
+                    return Err(std::convert::From::from(::derive_builder::ExactlyOneFieldError::new(std::vec![#(#group_field_name_strings),*])));
+                }
+            }
+        );
+    }
+
+    Ok(quote! {
+        #(#checks)*
+    })
+}
+
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    let DeriveInput { ident: struct_name, vis, generics, data, attrs, .. } = derive_input;
+
+    let struct_options = match struct_options(&attrs) {
+        Ok(struct_options) => struct_options,
+        Err(error) => {
+            return error
+                .to_compile_error()
+                .into();
+        },
+    };
+
+    if let Data::Struct(data) = data {
+        // The fields of a tuple struct are treated as if they were named `field_0`, `field_1` and
+        // so on, and only put back in position when the struct is built.
+        let (fields, is_tuple_struct) = match data.fields {
+            Fields::Named(fields) => (Some(fields.named), false),
+            Fields::Unnamed(fields) => {
+                let fields = fields.unnamed
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, mut field)| {
+                        field.ident = Some(format_ident!("field_{}", index, span = field.ty.span()));
+                        field
+                    })
+                    .collect();
+                (Some(fields), true)
+            },
+            Fields::Unit => (None, false),
+        };
+
+        if let Some(fields) = fields {
+
+            let mut builder_struct_members = Vec::with_capacity(fields.len());
+            let mut builder_function_initializers = Vec::with_capacity(fields.len());
+            let mut builder_function_members = Vec::with_capacity(fields.len());
+            let mut build_member_variable_inits = Vec::with_capacity(fields.len());
+            let mut build_owned_member_variable_inits = Vec::with_capacity(fields.len());
+            let mut missing_field_checks = Vec::with_capacity(fields.len());
+            let mut build_struct_member_initializers = Vec::with_capacity(fields.len());
+
+            // In owned mode, setters take and return the builder by value so that calls can be
+            // chained from a temporary all the way through to `build`.
+            let (setter_receiver, setter_return_type) =
+                if struct_options.owned {
+                    (quote! { mut self }, quote! { Self })
+                } else {
+                    (quote! { &mut self }, quote! { &mut Self })
+                };
+
+            let mut fields_with_options = Vec::with_capacity(fields.len());
+
+            // The local that `build` collects the names of missing fields in. Like the locals that
+            // hold the field values, it has a mixed-site span to keep it out of reach of the user's
+            // `default` expressions.
+            let missing_fields = Ident::new("missing_fields", Span::mixed_site());
+
+            for field in fields {
+                match field_options(&field.attrs) {
+                    Ok(field_options) => fields_with_options.push((field, field_options)),
+                    Err(error) => {
+                        return error
+                            .to_compile_error()
+                            .into();
+                    },
+                }
+            }
+
+            if let Err(error) = check_each_name_collisions(&fields_with_options) {
+                return error
+                    .to_compile_error()
+                    .into();
+            }
+
+            let check_exactly_one_fields = match exactly_one_checks(&struct_options.exactly_one_groups, &fields_with_options) {
+                Ok(checks) => checks,
+                Err(error) => {
+                    return error
+                        .to_compile_error()
+                        .into();
+                },
+            };
+
+            for (field_index, (field, field_options)) in fields_with_options.into_iter().enumerate() {
+                let generate_all_at_once_member_builder = has_all_at_once_setter(&field, &field_options);
+
+                let Field { ident: field_name, ty: field_type, .. } = field;
+
+                if let Some(field_name) = field_name {
+                    // `build` holds each value in a local before constructing the struct. Its
+                    // mixed-site span keeps it out of reach of the user's `default` expressions.
+                    let mut field_local = field_name.clone();
+                    field_local.set_span(Span::mixed_site());
+
+                    let build_struct_member_initializer =
+                        if is_tuple_struct {
+                            let field_index = syn::Index::from(field_index);
+                            quote! {
+                                #field_index: #field_local,
+                            }
+                        } else {
+                            quote! {
+                                #field_name: #field_local,
+                            }
+                        };
+
+                    let FieldOptions { attr_meta, each_name: vec_builder_name_value, default, setter_into, setter_boxed, skip, .. } = field_options;
+
+                    // Skipped fields have no setters in the builder, and are always initialized from
+                    // their default. The builder only keeps a marker of their type, so that a generic
+                    // parameter used by nothing but a skipped field is still used by the builder.
+                    if skip {
+                        builder_struct_members.push(
+                            quote! {
+                                #field_name: std::marker::PhantomData<#field_type>,
+                            }
+                        );
+
+                        builder_function_initializers.push(
+                            quote! {
+                                #field_name: std::marker::PhantomData,
+                            }
+                        );
+
+                        let default = match default {
+                            Some(default) => quote! { #default },
+                            None => quote! { std::default::Default::default() },
+                        };
+
+                        let member_variable_init = quote! {
+                            let #field_local = #default;
+                        };
+
+                        build_member_variable_inits.push(member_variable_init.clone());
+                        build_owned_member_variable_inits.push(member_variable_init);
+
+                        build_struct_member_initializers.push(build_struct_member_initializer);
+
+                        continue;
+                    }
+
+                    let vec_builder_name_ident = vec_builder_name_value.as_ref().map(each_setter_ident);
+
+                    let is_built_vec = vec_builder_name_ident.is_some();
+
+                    let option_inner_type = inner_type(&field_type, "Option");
+
+                    // For an `Option<Vec<T>>` field, the `each` setter fills the `Vec<T>` inside the
+                    // option. The first item creates it, and a field that is never touched stays `None`.
+                    let each_collection = EachCollection::from_type(option_inner_type.unwrap_or(&field_type));
+
+                    if let (Some(attr_meta), None) = (&attr_meta, &each_collection) {
+                        if is_built_vec {
+                            let message = format!(
+                                "`builder(each)` requires a `Vec<_>`, `HashSet<_>` or `HashMap<_, _>` field, but field `{}` is `{}`; change the field type or remove the attribute",
+                                field_name.unraw(),
+                                type_to_string(&field_type),
+                            );
+
+                            return syn::Error::new_spanned(attr_meta, message)
+                                .to_compile_error()
+                                .into();
+                        }
+                    }
+
+                    let builder_member_type: Type =
+                        match option_inner_type {
+                            Some(_) => field_type.clone(),
+                            None => parse_quote! { std::option::Option<#field_type> },
+                        };
+
+                    let builder_function_arg_type = option_inner_type.unwrap_or(&field_type).clone();
+
+                    // With `setter(boxed)`, the setter for a `Box<T>` or `Option<Box<T>>` field takes
+                    // a `T` and boxes it.
+                    let boxed_setter_arg_type =
+                        match inner_type(&builder_function_arg_type, "Box") {
+                            _ if !setter_boxed => None,
+                            Some(boxed_type) => Some(boxed_type.clone()),
+                            None => {
+                                let message = format!(
+                                    "`setter(boxed)` requires a `Box<_>` or `Option<Box<_>>` field, but field `{}` is `{}`",
+                                    field_name.unraw(),
+                                    type_to_string(&field_type),
+                                );
+
+                                return syn::Error::new_spanned(&attr_meta, message)
+                                    .to_compile_error()
+                                    .into();
+                            },
+                        };
+
+                    let each_collection = if is_built_vec { each_collection } else { None };
+
+                    let is_optional = option_inner_type.is_some();
+
+                    builder_struct_members.push(
+                        quote! {
+                            #field_name: #builder_member_type,
+                        }
+                    );
+
+                    builder_function_initializers.push(
+                        quote! {
+                            #field_name: None,
+                        }
+                    );
+
+                    if generate_all_at_once_member_builder {
+                        let setter_doc = format!("Sets the `{}` field.", field_name.unraw());
+
+                        let setter_into = setter_into || struct_options.setter_into;
+
+                        builder_function_members.push(
+                            if let Some(boxed_setter_arg_type) = &boxed_setter_arg_type {
+                                let (setter_param_type, setter_value) =
+                                    if setter_into {
+                                        (quote! { impl std::convert::Into<#boxed_setter_arg_type> }, quote! { #field_name.into() })
+                                    } else {
+                                        (quote! { #boxed_setter_arg_type }, quote! { #field_name })
+                                    };
+
+                                quote! {
+                                    #[doc = #setter_doc]
+                                    #vis fn #field_name(#setter_receiver, #field_name: #setter_param_type) -> #setter_return_type {
+                                        self.#field_name = Some(std::boxed::Box::new(#setter_value));
+                                        self
+                                    }
+                                }
+                            } else if setter_into {
+                                quote! {
+                                    #[doc = #setter_doc]
+                                    #vis fn #field_name(#setter_receiver, #field_name: impl std::convert::Into<#builder_function_arg_type>) -> #setter_return_type {
+                                        self.#field_name = Some(#field_name.into());
+                                        self
+                                    }
+                                }
+                            } else {
+                                quote! {
+                                    #[doc = #setter_doc]
+                                    #vis fn #field_name(#setter_receiver, #field_name: #builder_function_arg_type) -> #setter_return_type {
+                                        self.#field_name = Some(#field_name);
+                                        self
+                                    }
+                                }
+                            }
+                        );
+                    }
+
+                    if struct_options.getters {
+                        let getter_name = format_ident!("get_{}", field_name);
+                        let getter_doc = format!("Returns the value of the `{}` field, if it has been set.", field_name.unraw());
+
+                        builder_function_members.push(
+                            quote! {
+                                #[doc = #getter_doc]
+                                #vis fn #getter_name(&self) -> std::option::Option<&#builder_function_arg_type> {
+                                    self.#field_name.as_ref()
+                                }
+                            }
+                        );
+                    }
+
+                    if struct_options.clearers {
+                        let clearer_name = format_ident!("clear_{}", field_name);
+                        let clearer_doc = format!("Resets the `{}` field to unset.", field_name.unraw());
+
+                        builder_function_members.push(
+                            quote! {
+                                #[doc = #clearer_doc]
+                                #vis fn #clearer_name(#setter_receiver) -> #setter_return_type {
+                                    self.#field_name = None;
+                                    self
+                                }
+                            }
+                        );
+                    }
+
+                    let none_arm = match (&default, &each_collection) {
+                        (Some(default), _) => quote! { #default },
+                        (None, Some(_)) => quote! { std::default::Default::default() },
+                        (None, None) => {
+                            // Required fields are all checked up front, so that a single error can
+                            // report every field that is missing.
+                            if !is_optional {
+                                let field_name_string = field_name.unraw().to_string();
+                                missing_field_checks.push(
+                                    quote! {
+                                        if self.#field_name.is_none() {
+                                            #missing_fields.push(#field_name_string);
+                                        }
+                                    }
+                                );
+                            }
+
+                            quote! { unreachable!() }
+                        }
+                    };
+
+                    // `build` takes each value out of the builder through `&mut self`, while `build_owned`
+                    // moves it out of the consumed builder; otherwise the two are identical.
+                    let member_variable_init = |field_value: proc_macro2::TokenStream| {
+                        if is_optional && default.is_none() {
+                            quote! {
+                                let #field_local = #field_value;
+                            }
+                        } else if is_optional {
+                            quote! {
+                                let #field_local = match #field_value {
+                                    Some(#field_local) => Some(#field_local),
+                                    None => #none_arm,
+                                };
+                            }
+                        } else {
+                            quote! {
+                                let #field_local = match #field_value {
+                                    Some(#field_local) => #field_local,
+                                    None => #none_arm,
+                                };
+                            }
+                        }
+                    };
+
+                    build_member_variable_inits.push(member_variable_init(quote! { self.#field_name.take() }));
+                    build_owned_member_variable_inits.push(member_variable_init(quote! { self.#field_name }));
+
+                    build_struct_member_initializers.push(build_struct_member_initializer);
+
+                    if let (Some(vec_builder_name), Some(each_collection)) = (&vec_builder_name_ident, &each_collection) {
+                        let each_setter_params = each_collection.setter_params();
+                        let each_insert_call = each_collection.insert_call();
+                        let mut each_setter_doc = each_collection.setter_doc(&field_name);
+                        if !generate_all_at_once_member_builder {
+                            each_setter_doc.push_str("\n\nThis replaces the setter for the whole field, which would have the same name.");
+                        }
+
+                        builder_function_members.push(
+                            quote! {
+                                #[doc = #each_setter_doc]
+                                #vis fn #vec_builder_name(#setter_receiver, #each_setter_params) -> #setter_return_type {
+                                    self.#field_name
+                                        .get_or_insert_with(std::default::Default::default)
+                                        .#each_insert_call;
+                                    self
+                                }
+                            }
+                        );
+                    }
+                }
+            }
+
+            let builder_name = match struct_options.builder_name {
+                Some(builder_name) => builder_name,
+                None => format_ident!("{}Builder", struct_name),
+            };
+
+            let builder_fn_name = struct_options.builder_fn_name.unwrap_or_else(|| format_ident!("builder"));
+            let build_fn_name = struct_options.build_fn_name.unwrap_or_else(|| format_ident!("build"));
+
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let ty_turbofish = ty_generics.as_turbofish();
+
+            let error_type = match struct_options.error_type {
+                Some(error_type) => quote! { #error_type },
+                None => quote! { std::boxed::Box<dyn std::error::Error> },
+            };
+
+            let builder_fn_doc = format!("Creates a [`{}`] with every field unset.", builder_name);
+            let builder_struct_doc = format!("A builder for [`{}`].", struct_name);
+            let build_doc = format!("Builds a new [`{}`].\n\nReturns an error naming every required field that has not been set.", struct_name);
+            let build_owned_doc = format!("Builds a new [`{}`], consuming the builder.\n\nReturns an error naming every required field that has not been set.", struct_name);
+
+            let (build_doc, build_owned_doc) = match &struct_options.validate {
+                Some(validate) => {
+                    let validate_doc = format!("\n\nOnce every required field is set, the builder is checked with `{}`, and its error is returned if it fails.", quote!(#validate).to_string().replace(' ', ""));
+                    (build_doc + &validate_doc, build_owned_doc + &validate_doc)
+                },
+                None => (build_doc, build_owned_doc),
+            };
+
+            let (build_receiver, build_doc, build_member_variable_inits) =
+                if struct_options.owned {
+                    (quote! { self }, &build_owned_doc, &build_owned_member_variable_inits)
+                } else {
+                    (quote! { &mut self }, &build_doc, &build_member_variable_inits)
+                };
+
+            // The validation function sees the complete builder, before any value is taken out of
+            // it, so that a rejected builder can still be corrected and built again.
+            let error = Ident::new("error", Span::mixed_site());
+            let validate_call = |builder: proc_macro2::TokenStream| {
+                match &struct_options.validate {
+                    Some(validate) => quote! {
+                        if let Err(#error) = #validate(#builder) {
+                            return Err(std::convert::From::from(#error));
+                        }
+                    },
+                    None => quote! {},
+                }
+            };
+
+            let (check_build_valid, check_build_owned_valid) =
+                if struct_options.owned {
+                    (validate_call(quote! { &self }), validate_call(quote! { &self }))
+                } else {
+                    (validate_call(quote! { &*self }), validate_call(quote! { &self }))
+                };
+
+            let check_missing_fields =
+                if missing_field_checks.is_empty() {
+                    quote! {}
+                } else {
+                    quote! {
+                        let mut #missing_fields: std::vec::Vec<&'static str> = std::vec::Vec::new();
+
+                        #(#missing_field_checks)*
+
+                        if !#missing_fields.is_empty() {
+                            return Err(std::convert::From::from(::derive_builder::UninitializedFieldError::new(#missing_fields)));
+                        }
+                    }
+                };
+
+            // A program is not expected to use every setter, so none of the generated items should
+            // produce dead code warnings. The attribute on each impl block covers its methods.
+            let expanded = quote! {
+                #[allow(dead_code)]
+                impl #impl_generics #struct_name #ty_generics #where_clause {
+                    #[doc = #builder_fn_doc]
+                    #vis fn #builder_fn_name() -> #builder_name #ty_generics {
+                        <#builder_name #ty_generics as std::default::Default>::default()
+                    }
+                }
+
+                #[doc = #builder_struct_doc]
+                #[allow(dead_code)]
+                #vis struct #builder_name #generics #where_clause {
+                    #(#builder_struct_members)*
+                }
+
+                impl #impl_generics std::default::Default for #builder_name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        #builder_name {
+                            #(#builder_function_initializers)*
+                        }
+                    }
+                }
+
+                #[allow(dead_code)]
+                impl #impl_generics #builder_name #ty_generics #where_clause {
+                    #(#builder_function_members)*
+
+                    #[doc = #build_doc]
+                    #vis fn #build_fn_name(#build_receiver) -> std::result::Result<#struct_name #ty_generics, #error_type> {
+                        #check_missing_fields
+
+                        #check_exactly_one_fields
+
+                        #check_build_valid
+
+                        #(#build_member_variable_inits)*
+
+                        Ok(#struct_name #ty_turbofish {
+                            #(#build_struct_member_initializers)*
+                        })
+                    }
+
+                    #[doc = #build_owned_doc]
+                    #vis fn build_owned(self) -> std::result::Result<#struct_name #ty_generics, #error_type> {
+                        #check_missing_fields
+
+                        #check_exactly_one_fields
+
+                        #check_build_owned_valid
+
+                        #(#build_owned_member_variable_inits)*
+
+                        Ok(#struct_name #ty_turbofish {
+                            #(#build_struct_member_initializers)*
+                        })
+                    }
+                }
+            };
+
+            return TokenStream::from(expanded)
+        }
+    }
+
+    // Enums, unions and unit structs have no fields for a builder to set
+    syn::Error::new_spanned(&struct_name, "Builder can only be derived for structs with named or tuple fields")
+        .to_compile_error()
+        .into()
+}
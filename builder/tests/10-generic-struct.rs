@@ -0,0 +1,36 @@
+// Generic parameters on the input struct should carry over to the generated
+// builder. The builder struct, its inherent impl, the `builder()` constructor
+// and the return type of `build()` all need to mention the same type
+// parameters as the original struct.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Wrapper<T> {
+    value: T,
+    label: Option<String>,
+    #[builder(each = "extra")]
+    extras: Vec<T>,
+}
+
+fn main() {
+    let wrapper = Wrapper::<u32>::builder()
+        .value(7)
+        .extra(8)
+        .extra(9)
+        .build()
+        .unwrap();
+
+    assert_eq!(wrapper.value, 7);
+    assert_eq!(wrapper.label, None);
+    assert_eq!(wrapper.extras, vec![8, 9]);
+
+    let wrapper: Wrapper<&str> = Wrapper::builder()
+        .value("seven")
+        .label("number".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(wrapper.value, "seven");
+    assert_eq!(wrapper.label.as_deref(), Some("number"));
+}
@@ -0,0 +1,26 @@
+// Lifetime parameters need to be threaded through the generated code exactly
+// like type parameters, so that a builder can hold borrowed data until `build()`
+// hands it over to the struct.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Request<'a> {
+    method: &'a str,
+    path: &'a str,
+    body: Option<&'a [u8]>,
+}
+
+fn main() {
+    let path = String::from("/index.html");
+
+    let request = Request::builder()
+        .method("GET")
+        .path(&path)
+        .build()
+        .unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/index.html");
+    assert_eq!(request.body, None);
+}
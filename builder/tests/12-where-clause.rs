@@ -0,0 +1,35 @@
+// Bounds written in a where-clause (and inline on the parameter) are required
+// to name the struct at all, so the generated builder has to repeat them on its
+// own definition and on every impl block.
+
+use derive_builder::Builder;
+use std::fmt::Display;
+
+#[derive(Builder)]
+pub struct Labeled<K: Clone, V>
+where
+    V: Display,
+{
+    key: K,
+    value: V,
+}
+
+impl<K: Clone, V> Labeled<K, V>
+where
+    V: Display,
+{
+    fn describe(&self) -> String {
+        format!("{}", self.value)
+    }
+}
+
+fn main() {
+    let labeled = Labeled::builder()
+        .key(1u8)
+        .value(2.5f64)
+        .build()
+        .unwrap();
+
+    assert_eq!(labeled.key, 1);
+    assert_eq!(labeled.describe(), "2.5");
+}
@@ -0,0 +1,44 @@
+// The `each` attribute is not limited to `Vec`. A `HashSet` field gets a
+// one-at-a-time setter that inserts a single item, and a `HashMap` field gets
+// one that takes a key and a value. Like vectors, sets and maps that are never
+// touched are built as empty collections rather than reported as missing.
+
+use derive_builder::Builder;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Builder)]
+pub struct Config {
+    name: String,
+    #[builder(each = "tag")]
+    tags: HashSet<String>,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+}
+
+fn main() {
+    let config = Config::builder()
+        .name("server".to_owned())
+        .tag("web".to_owned())
+        .tag("prod".to_owned())
+        .tag("web".to_owned())
+        .env("PORT".to_owned(), "8080".to_owned())
+        .env("HOST".to_owned(), "localhost".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(config.name, "server");
+    assert_eq!(config.tags.len(), 2);
+    assert!(config.tags.contains("web"));
+    assert!(config.tags.contains("prod"));
+    assert_eq!(config.env.len(), 2);
+    assert_eq!(config.env["PORT"], "8080");
+    assert_eq!(config.env["HOST"], "localhost");
+
+    let config = Config::builder()
+        .name("empty".to_owned())
+        .build()
+        .unwrap();
+
+    assert!(config.tags.is_empty());
+    assert!(config.env.is_empty());
+}
@@ -0,0 +1,42 @@
+// A required field may be given a fallback with #[builder(default = "...")].
+// The string is parsed as a Rust expression, and `build()` uses it in place of
+// reporting the field as missing when its setter was never called.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    host: String,
+    #[builder(default = "8080")]
+    port: u16,
+    #[builder(default = "vec![\"localhost\".to_owned()]")]
+    aliases: Vec<String>,
+    #[builder(default = "Some(30)")]
+    timeout: Option<u32>,
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("example.com".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(server.host, "example.com");
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.aliases, vec!["localhost"]);
+    assert_eq!(server.timeout, Some(30));
+
+    let server = Server::builder()
+        .host("example.com".to_owned())
+        .port(443)
+        .aliases(Vec::new())
+        .timeout(5)
+        .build()
+        .unwrap();
+
+    assert_eq!(server.port, 443);
+    assert!(server.aliases.is_empty());
+    assert_eq!(server.timeout, Some(5));
+
+    assert!(Server::builder().build().is_err());
+}
@@ -0,0 +1,14 @@
+// Fields with an `each` setter already fall back to an empty collection, so
+// combining `each` with `default` is rejected rather than silently preferring
+// one of them.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg", default = "vec![]")]
+    args: Vec<String>,
+}
+
+fn main() {}
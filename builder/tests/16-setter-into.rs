@@ -0,0 +1,47 @@
+// With #[builder(setter(into))] a setter accepts anything convertible into the
+// field type, so a `String` field can be set from a `&str`. For `Option`
+// fields the conversion targets the inner type. Writing the attribute on the
+// struct turns it on for every field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Person {
+    #[builder(setter(into))]
+    name: String,
+    #[builder(setter(into))]
+    nickname: Option<String>,
+    age: u32,
+}
+
+#[derive(Builder)]
+#[builder(setter(into))]
+pub struct Address {
+    street: String,
+    city: String,
+    zip: Option<u64>,
+}
+
+fn main() {
+    let person = Person::builder()
+        .name("Ferris")
+        .nickname("crab")
+        .age(8)
+        .build()
+        .unwrap();
+
+    assert_eq!(person.name, "Ferris");
+    assert_eq!(person.nickname.as_deref(), Some("crab"));
+    assert_eq!(person.age, 8);
+
+    let address = Address::builder()
+        .street("1 Main St")
+        .city(String::from("Springfield"))
+        .zip(12345u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(address.street, "1 Main St");
+    assert_eq!(address.city, "Springfield");
+    assert_eq!(address.zip, Some(12345));
+}
@@ -0,0 +1,44 @@
+// Besides `build(&mut self)`, the builder offers `build_owned(self)`, which
+// consumes the builder and moves the values out instead of taking them through
+// a mutable reference. Optional fields, `each` collections and defaults behave
+// the same way in both.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    priority: u8,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned());
+    builder.arg("build".to_owned());
+
+    let command = builder.build_owned().unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.priority, 1);
+
+    let mut builder = Command::builder();
+    builder
+        .executable("rustc".to_owned())
+        .current_dir("..".to_owned())
+        .priority(3);
+
+    let command = builder.build_owned().unwrap();
+
+    assert_eq!(command.executable, "rustc");
+    assert!(command.args.is_empty());
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.priority, 3);
+
+    assert!(Command::builder().build_owned().is_err());
+}
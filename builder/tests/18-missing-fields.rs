@@ -0,0 +1,40 @@
+// When several required fields are unset, `build()` reports all of them in a
+// single error instead of stopping at the first one. Optional fields are never
+// missing and `each` collections default to empty, so neither appears in the
+// message. Because the check happens before anything is taken out of the
+// builder, a failed `build()` leaves the builder untouched.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    env: Vec<String>,
+    current_dir: Option<String>,
+    priority: u8,
+}
+
+fn main() {
+    let error = Command::builder().build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: executable, env, priority");
+
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+
+    let error = builder.build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: env, priority");
+
+    builder.env(vec![]).priority(1);
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+
+    let mut builder = Command::builder();
+    builder.priority(2);
+
+    let error = builder.build_owned().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: executable, env");
+}
@@ -0,0 +1,34 @@
+// The builder implements `Default`, starting out with every field unset, so it
+// can be created without going through `builder()` -- for example when it is
+// embedded in another struct that itself derives `Default`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Point<T> {
+    x: T,
+    y: T,
+    label: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Canvas {
+    pending_point: PointBuilder<i32>,
+}
+
+fn main() {
+    let point = PointBuilder::default().x(1).y(2).build().unwrap();
+
+    assert_eq!(point.x, 1);
+    assert_eq!(point.y, 2);
+    assert_eq!(point.label, None);
+
+    let mut canvas = Canvas::default();
+    canvas.pending_point.x(3).y(4).label("corner".to_owned());
+
+    let point = canvas.pending_point.build().unwrap();
+
+    assert_eq!(point.x, 3);
+    assert_eq!(point.y, 4);
+    assert_eq!(point.label.as_deref(), Some("corner"));
+}
@@ -0,0 +1,30 @@
+// The generated builder is called `{Struct}Builder` by default. A struct-level
+// #[builder(name = "...")] attribute picks a different name for it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(name = "CommandFactory")]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+fn make_command(factory: &mut CommandFactory) -> Command {
+    factory.build().unwrap()
+}
+
+fn main() {
+    let mut factory: CommandFactory = Command::builder();
+    factory.executable("cargo".to_owned());
+
+    let command = make_command(&mut factory);
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir, None);
+
+    let command = CommandFactory::default()
+        .executable("rustc".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "rustc");
+}
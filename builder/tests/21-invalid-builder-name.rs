@@ -0,0 +1,12 @@
+// The builder name has to be usable as a type name, so anything that does not
+// parse as an identifier is reported at the string literal.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(name = "Command Builder")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Optional fields and `each` collections are recognized even when their types
+// are written out in full, such as `std::option::Option<T>`, rather than
+// relying on the prelude. The setter for a fully-qualified `Option` field
+// still takes the inner type.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: ::std::string::String,
+    #[builder(each = "arg")]
+    args: std::vec::Vec<String>,
+    #[builder(each = "tag")]
+    tags: std::collections::HashSet<String>,
+    current_dir: std::option::Option<String>,
+    timeout: core::option::Option<u32>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .tag("ci".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert!(command.tags.contains("ci"));
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.timeout, None);
+}
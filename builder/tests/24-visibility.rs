@@ -0,0 +1,41 @@
+// The builder and its methods take on the visibility of the struct they are
+// derived for, so a builder for a public struct defined in one module can be
+// used from another.
+
+mod shapes {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Rectangle {
+        pub width: u32,
+        pub height: u32,
+        #[builder(each = "tag")]
+        pub tags: Vec<String>,
+    }
+
+    pub mod inner {
+        use derive_builder::Builder;
+
+        #[derive(Builder)]
+        pub(crate) struct Circle {
+            pub(crate) radius: u32,
+        }
+    }
+}
+
+use shapes::inner::{Circle, CircleBuilder};
+use shapes::{Rectangle, RectangleBuilder};
+
+fn main() {
+    let mut builder: RectangleBuilder = Rectangle::builder();
+    builder.width(3).height(4).tag("square-ish".to_owned());
+
+    let rectangle = builder.build().unwrap();
+    assert_eq!(rectangle.width, 3);
+    assert_eq!(rectangle.height, 4);
+    assert_eq!(rectangle.tags, vec!["square-ish"]);
+
+    let circle = CircleBuilder::default().radius(2).build().unwrap();
+    let _: &Circle = &circle;
+    assert_eq!(circle.radius, 2);
+}
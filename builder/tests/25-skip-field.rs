@@ -0,0 +1,28 @@
+// A field marked #[builder(skip)] gets no setter, and the builder holds no value
+// for it. `build()` initializes it from its `default` expression, or from
+// `Default` when none is given.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Connection {
+    host: String,
+    port: u16,
+    #[builder(skip, default = "String::from(\"unused\")")]
+    session_id: String,
+    #[builder(skip)]
+    retries: u32,
+}
+
+fn main() {
+    let connection = Connection::builder()
+        .host("localhost".to_owned())
+        .port(5432)
+        .build()
+        .unwrap();
+
+    assert_eq!(connection.host, "localhost");
+    assert_eq!(connection.port, 5432);
+    assert_eq!(connection.session_id, "unused");
+    assert_eq!(connection.retries, 0);
+}
@@ -0,0 +1,13 @@
+// A skipped field has no setters at all, so asking for a one-at-a-time setter
+// on it is a mistake.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(skip, each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+// By default `build()` returns a `Box<dyn Error>`. A struct-level
+// #[builder(error = "...")] attribute names a different error type instead;
+// it is created from the crate's `UninitializedFieldError`, which carries the
+// names of the missing fields.
+
+use derive_builder::{Builder, UninitializedFieldError};
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Missing(Vec<&'static str>),
+}
+
+impl From<UninitializedFieldError> for ConfigError {
+    fn from(error: UninitializedFieldError) -> Self {
+        ConfigError::Missing(error.field_names().to_vec())
+    }
+}
+
+#[derive(Builder)]
+#[builder(error = "ConfigError")]
+pub struct Config {
+    name: String,
+    port: u16,
+    verbose: Option<bool>,
+}
+
+#[derive(Builder)]
+#[builder(error = "UninitializedFieldError")]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let error = Config::builder().port(80).build().err().unwrap();
+    assert_eq!(error, ConfigError::Missing(vec!["name"]));
+
+    let config = Config::builder()
+        .name("server".to_owned())
+        .port(80)
+        .build()
+        .unwrap();
+    assert_eq!(config.name, "server");
+    assert_eq!(config.port, 80);
+    assert_eq!(config.verbose, None);
+
+    let error = Point::builder().build().err().unwrap();
+    assert_eq!(error.field_names(), ["x", "y"]);
+    assert_eq!(error.to_string(), "missing fields: x, y");
+}
@@ -0,0 +1,35 @@
+// With the struct-level #[builder(getters)] attribute, the builder also gets a
+// `get_{field}` method per field for reading back what has been set so far.
+// The getter returns `None` until the field is set, and for `Option` fields it
+// refers to the inner value just like the setter takes it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(getters)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+
+    let executable: Option<&String> = builder.get_executable();
+    assert_eq!(executable, None);
+    assert_eq!(builder.get_args(), None);
+    assert_eq!(builder.get_current_dir(), None);
+
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+
+    assert_eq!(builder.get_executable().map(String::as_str), Some("cargo"));
+    let args: Option<&Vec<String>> = builder.get_args();
+    assert_eq!(args, Some(&vec!["build".to_owned()]));
+    let current_dir: Option<&String> = builder.get_current_dir();
+    assert_eq!(current_dir.map(String::as_str), Some(".."));
+}
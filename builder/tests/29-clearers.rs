@@ -0,0 +1,51 @@
+// With the struct-level #[builder(clearers)] attribute, the builder gets a
+// `clear_{field}` method per field that puts it back into the unset state.
+// That makes it easy to reuse one builder for several similar values. A
+// cleared `each` collection falls back to being empty.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(clearers)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+
+    let command = builder.build_owned().unwrap();
+    assert_eq!(command.args, vec!["build"]);
+
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .clear_args()
+        .clear_current_dir()
+        .arg("test".to_owned());
+
+    let command = builder.build_owned().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["test"]);
+    assert_eq!(command.current_dir, None);
+
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    builder.clear_args().clear_executable();
+
+    let error = builder.build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: executable");
+
+    builder.executable("rustc".to_owned());
+    let command = builder.build().unwrap();
+    assert!(command.args.is_empty());
+}
@@ -0,0 +1,14 @@
+// An `each` setter named after a different field would be generated alongside
+// that field's own setter, and the two would clash. The collision is reported
+// at the `each` name rather than as a duplicate definition in generated code.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Post {
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+    tag: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Two `each` setters with the same name on different fields collide in the
+// same way.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "add")]
+    args: Vec<String>,
+    #[builder(each = "add")]
+    env: Vec<String>,
+}
+
+fn main() {}
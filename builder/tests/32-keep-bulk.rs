@@ -0,0 +1,38 @@
+// A field with an `each` setter also keeps the setter for its whole value as
+// long as the two have different names. #[builder(each = "...", keep_bulk)]
+// makes that explicit, and both setters can then be mixed on the same field.
+// Without `keep_bulk`, an `each` setter named after the field still replaces
+// the whole-value setter.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg", keep_bulk)]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec!["build".to_owned(), "--release".to_owned()])
+        .arg("--locked".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build", "--release", "--locked"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("test".to_owned())
+        .args(vec!["check".to_owned()])
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["check"]);
+}
@@ -0,0 +1,13 @@
+// `keep_bulk` asks for the whole-value setter to be kept, which is impossible
+// when the `each` setter has the field's own name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "args", keep_bulk)]
+    args: Vec<String>,
+}
+
+fn main() {}
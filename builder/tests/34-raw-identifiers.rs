@@ -0,0 +1,39 @@
+// Fields may be named with raw identifiers such as `r#type`. Setters, getters
+// and `each` setters are still generated with valid names, and error messages
+// refer to the field by its plain name. An `each` name that is a keyword, like
+// "loop", becomes a raw identifier as well.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(getters)]
+pub struct Token {
+    r#type: String,
+    r#async: Option<bool>,
+    #[builder(each = "r#match")]
+    matches: Vec<String>,
+    #[builder(each = "loop")]
+    loops: Vec<u32>,
+}
+
+fn main() {
+    let error = Token::builder().build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: type");
+
+    let mut builder = Token::builder();
+    builder
+        .r#type("ident".to_owned())
+        .r#async(true)
+        .r#match("a".to_owned())
+        .r#loop(1)
+        .r#loop(2);
+
+    assert_eq!(builder.get_type().map(String::as_str), Some("ident"));
+    assert_eq!(builder.get_async(), Some(&true));
+
+    let token = builder.build().unwrap();
+    assert_eq!(token.r#type, "ident");
+    assert_eq!(token.r#async, Some(true));
+    assert_eq!(token.matches, vec!["a"]);
+    assert_eq!(token.loops, vec![1, 2]);
+}
@@ -0,0 +1,30 @@
+// Const generic parameters are carried over to the builder just like type and
+// lifetime parameters, including any default value they declare.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Buf<const N: usize> {
+    data: [u8; N],
+    len: Option<usize>,
+}
+
+#[derive(Builder)]
+pub struct Grid<T, const W: usize, const H: usize = 2> {
+    cells: [[T; W]; H],
+}
+
+fn main() {
+    let mut builder: BufBuilder<4> = Buf::<4>::builder();
+    builder.data([1, 2, 3, 4]);
+
+    let buf: Buf<4> = builder.build().unwrap();
+    assert_eq!(buf.data, [1, 2, 3, 4]);
+    assert_eq!(buf.len, None);
+
+    let grid: Grid<char, 3> = Grid::builder()
+        .cells([['a', 'b', 'c'], ['d', 'e', 'f']])
+        .build()
+        .unwrap();
+    assert_eq!(grid.cells[1][2], 'f');
+}
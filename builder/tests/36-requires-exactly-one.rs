@@ -0,0 +1,42 @@
+// Some optional fields are mutually exclusive but one of them is still needed.
+// A struct-level #[builder(requires_exactly_one(...))] attribute makes
+// `build()` fail unless exactly one field of the group has been set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(requires_exactly_one(path, url, inline))]
+pub struct Source {
+    name: String,
+    path: Option<String>,
+    url: Option<String>,
+    inline: Option<Vec<u8>>,
+}
+
+fn main() {
+    let error = Source::builder()
+        .name("none".to_owned())
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "exactly one of path, url, inline must be set");
+
+    let error = Source::builder()
+        .name("two".to_owned())
+        .path("/tmp/data".to_owned())
+        .url("https://example.com/data".to_owned())
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "exactly one of path, url, inline must be set");
+
+    let source = Source::builder()
+        .name("one".to_owned())
+        .url("https://example.com/data".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(source.name, "one");
+    assert_eq!(source.path, None);
+    assert_eq!(source.url.as_deref(), Some("https://example.com/data"));
+    assert_eq!(source.inline, None);
+}
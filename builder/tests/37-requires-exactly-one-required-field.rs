@@ -0,0 +1,20 @@
+// Only `Option` fields can be left unset, so naming any other field (or a
+// field that does not exist) in a `requires_exactly_one` group is an error.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(requires_exactly_one(name, url))]
+pub struct Source {
+    name: String,
+    url: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(requires_exactly_one(path, link))]
+pub struct Target {
+    path: Option<String>,
+    url: Option<String>,
+}
+
+fn main() {}
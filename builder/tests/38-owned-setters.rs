@@ -0,0 +1,38 @@
+// With the struct-level #[builder(owned)] attribute, setters take the builder
+// by value and return it, and `build()` consumes it. The whole chain can then
+// be written as a single expression, and intermediate builders can be stored
+// by value.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(owned, clearers)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn with_defaults(builder: CommandBuilder) -> CommandBuilder {
+    builder.executable("cargo".to_owned()).arg("--locked".to_owned())
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .clear_current_dir()
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+
+    let builder = with_defaults(Command::builder());
+    let command = builder.arg("test".to_owned()).build_owned().unwrap();
+
+    assert_eq!(command.args, vec!["--locked", "test"]);
+}
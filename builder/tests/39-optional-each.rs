@@ -0,0 +1,44 @@
+// An `Option<Vec<T>>` field with an `each` attribute combines both behaviors.
+// The `each` setter pushes into the inner vector, creating it on first use.
+// The whole-value setter takes a `Vec<T>`. A field that is never touched is
+// built as `None` rather than as an empty vector.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Request {
+    url: String,
+    #[builder(each = "header")]
+    headers: Option<Vec<String>>,
+    #[builder(each = "cookie")]
+    cookies: Option<Vec<String>>,
+}
+
+fn main() {
+    let request = Request::builder()
+        .url("https://example.com".to_owned())
+        .header("Accept: */*".to_owned())
+        .header("Connection: close".to_owned())
+        .cookies(vec!["id=1".to_owned()])
+        .cookie("theme=dark".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(request.url, "https://example.com");
+    assert_eq!(
+        request.headers,
+        Some(vec!["Accept: */*".to_owned(), "Connection: close".to_owned()]),
+    );
+    assert_eq!(
+        request.cookies,
+        Some(vec!["id=1".to_owned(), "theme=dark".to_owned()]),
+    );
+
+    let request = Request::builder()
+        .url("https://example.com".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(request.headers, None);
+    assert_eq!(request.cookies, None);
+}
@@ -0,0 +1,14 @@
+// The `each` attribute only works on collections that the builder knows how to
+// fill one item at a time. Using it on any other type is reported at the
+// attribute, naming the field and its type.
+
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder)]
+pub struct Config {
+    #[builder(each = "tag")]
+    tags: BTreeMap<String, u32>,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// The `builder()` constructor and the `build()` method can be renamed with the
+// struct-level #[builder(builder_fn = "...")] and #[builder(build_fn = "...")]
+// attributes, for structs that already have methods with those names or fields
+// whose setters would collide with them.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(builder_fn = "make_builder", build_fn = "finish")]
+pub struct Job {
+    build: String,
+    retries: Option<u32>,
+}
+
+impl Job {
+    pub fn builder(&self) -> &str {
+        &self.build
+    }
+}
+
+fn main() {
+    let job = Job::make_builder()
+        .build("release".to_owned())
+        .retries(3)
+        .finish()
+        .unwrap();
+
+    assert_eq!(job.builder(), "release");
+    assert_eq!(job.retries, Some(3));
+}
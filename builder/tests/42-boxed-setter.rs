@@ -0,0 +1,45 @@
+// A field stored as `Box<T>` or `Option<Box<T>>` can use
+// #[builder(setter(boxed))] so that its setter takes the unboxed `T` and does
+// the boxing itself. An optional boxed field that is never set is still `None`.
+// `boxed` can be combined with `into`, in which case the setter accepts
+// anything that converts into `T`.
+
+use derive_builder::Builder;
+
+#[derive(Debug, PartialEq)]
+pub enum Node {
+    Leaf(u32),
+    Pair(Box<Node>, Box<Node>),
+}
+
+#[derive(Builder)]
+pub struct Tree {
+    #[builder(setter(boxed))]
+    root: Box<Node>,
+    #[builder(setter(boxed))]
+    fallback: Option<Box<Node>>,
+    #[builder(setter(into, boxed))]
+    label: Option<std::boxed::Box<String>>,
+}
+
+fn main() {
+    let tree = Tree::builder()
+        .root(Node::Pair(Box::new(Node::Leaf(1)), Box::new(Node::Leaf(2))))
+        .label("top")
+        .build()
+        .unwrap();
+
+    assert_eq!(*tree.root, Node::Pair(Box::new(Node::Leaf(1)), Box::new(Node::Leaf(2))));
+    assert_eq!(tree.fallback, None);
+    assert_eq!(tree.label.as_deref().map(String::as_str), Some("top"));
+
+    let tree = Tree::builder()
+        .root(Node::Leaf(3))
+        .fallback(Node::Leaf(4))
+        .build()
+        .unwrap();
+
+    assert_eq!(*tree.root, Node::Leaf(3));
+    assert_eq!(tree.fallback, Some(Box::new(Node::Leaf(4))));
+    assert_eq!(tree.label, None);
+}
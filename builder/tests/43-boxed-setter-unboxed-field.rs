@@ -0,0 +1,12 @@
+// The `boxed` setter option only makes sense on a field that holds a `Box`.
+// Anywhere else it is reported at the attribute, naming the field and its type.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Tree {
+    #[builder(setter(boxed))]
+    root: Option<String>,
+}
+
+fn main() {}
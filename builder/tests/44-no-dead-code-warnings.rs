@@ -0,0 +1,27 @@
+// A private struct whose builder is only partly used must not cause dead code
+// warnings for the setters, getters and other generated items that the program
+// never touches.
+
+#![deny(dead_code)]
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(getters, clearers)]
+struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert!(command.args.is_empty());
+    assert_eq!(command.current_dir, None);
+}
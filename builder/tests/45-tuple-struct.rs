@@ -0,0 +1,40 @@
+// A tuple struct gets a builder too. Its fields have no names, so each setter
+// is named after the position of its field: `field_0`, `field_1` and so on.
+// Options and `each` setters work the same as for named fields, and the built
+// struct has every value back in its original position.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Wrapper(
+    String,
+    Option<i32>,
+    #[builder(each = "tag")] Vec<String>,
+    #[builder(default = "7")] u8,
+);
+
+fn main() {
+    let wrapper = Wrapper::builder()
+        .field_0("name".to_owned())
+        .tag("a".to_owned())
+        .tag("b".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(wrapper.0, "name");
+    assert_eq!(wrapper.1, None);
+    assert_eq!(wrapper.2, vec!["a", "b"]);
+    assert_eq!(wrapper.3, 7);
+
+    let wrapper = Wrapper::builder()
+        .field_3(1)
+        .field_1(-5)
+        .field_0("other".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(wrapper.0, "other");
+    assert_eq!(wrapper.1, Some(-5));
+    assert!(wrapper.2.is_empty());
+    assert_eq!(wrapper.3, 1);
+}
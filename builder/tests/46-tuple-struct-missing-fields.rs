@@ -0,0 +1,23 @@
+// An unset required field of a tuple struct is reported by its position, the
+// same name that its setter has.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Pair(String, Option<String>, u32);
+
+fn main() {
+    let error = Pair::builder().build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: field_0, field_2");
+
+    let mut builder = Pair::builder();
+    builder.field_2(3);
+
+    let error = builder.build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: field_0");
+
+    let pair = builder.field_0("left".to_owned()).build().unwrap();
+    assert_eq!(pair.0, "left");
+    assert_eq!(pair.1, None);
+    assert_eq!(pair.2, 3);
+}
@@ -0,0 +1,12 @@
+// Deriving Builder on an enum is an error at the enum itself, rather than a
+// missing `builder` method wherever it is first called.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main() {}
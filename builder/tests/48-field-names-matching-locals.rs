@@ -0,0 +1,77 @@
+// Fields may have the same names as the parameters and locals in the generated
+// code: `item`, `key` and `value` in the `each` setters, and `missing_fields`
+// and `set_count` in `build()`. The locals of `build()`, including the ones
+// holding each field's value, are hygienic, so a `default` expression naming
+// something like a field sees the user's item rather than a generated local.
+// The same holds for a struct declared inside a function.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder)]
+#[builder(requires_exactly_one(set_count, value))]
+pub struct Inventory {
+    #[builder(each = "add")]
+    item: Vec<String>,
+    #[builder(each = "entry")]
+    key: HashMap<String, u32>,
+    value: Option<u32>,
+    set_count: Option<u32>,
+    missing_fields: String,
+}
+
+fn name() -> String {
+    "unnamed".to_owned()
+}
+
+#[derive(Builder)]
+pub struct Label {
+    name: String,
+    #[builder(default = "name()")]
+    alias: String,
+    #[builder(skip, default = "name().len()")]
+    width: usize,
+}
+
+fn main() {
+    let label = Label::builder().name("first".to_owned()).build().unwrap();
+    assert_eq!(label.name, "first");
+    assert_eq!(label.alias, "unnamed");
+    assert_eq!(label.width, 7);
+
+    #[derive(Builder)]
+    struct Local {
+        missing_fields: Vec<String>,
+        #[builder(default = "missing_fields_default()")]
+        count: u32,
+    }
+
+    fn missing_fields_default() -> u32 {
+        3
+    }
+
+    let local = Local::builder().missing_fields(vec![]).build().unwrap();
+    assert!(local.missing_fields.is_empty());
+    assert_eq!(local.count, 3);
+
+    let error = Local::builder().build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: missing_fields");
+
+    let inventory = Inventory::builder()
+        .add("apple".to_owned())
+        .add("pear".to_owned())
+        .entry("apple".to_owned(), 3)
+        .value(7)
+        .missing_fields("none".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(inventory.item, vec!["apple", "pear"]);
+    assert_eq!(inventory.key["apple"], 3);
+    assert_eq!(inventory.value, Some(7));
+    assert_eq!(inventory.set_count, None);
+    assert_eq!(inventory.missing_fields, "none");
+
+    let error = Inventory::builder().value(1).build().err().unwrap();
+    assert_eq!(error.to_string(), "missing fields: missing_fields");
+}
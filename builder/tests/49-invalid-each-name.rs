@@ -0,0 +1,14 @@
+// An `each` name has to become the name of a setter method, so a string that
+// isn't a valid identifier is reported at the string rather than making the
+// macro panic.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "not an ident")]
+    args: Vec<String>,
+}
+
+fn main() {}
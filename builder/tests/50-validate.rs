@@ -0,0 +1,51 @@
+// A struct-level #[builder(validate = "...")] attribute names a function that
+// checks the builder as a whole, for invariants that involve several fields.
+// `build()` calls it once every required field is set, and returns its error
+// if it fails. Nothing is taken out of the builder first, so a rejected
+// builder can be corrected and built again.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(validate = "Self::check")]
+pub struct Range {
+    start: u32,
+    end: u32,
+    step: Option<u32>,
+}
+
+impl RangeBuilder {
+    fn check(&self) -> Result<(), String> {
+        if self.start > self.end {
+            return Err(format!("start {} is after end {}", self.start.unwrap(), self.end.unwrap()));
+        }
+        if self.step == Some(0) {
+            return Err("step cannot be 0".to_owned());
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    let mut builder = Range::builder();
+    builder.start(5).end(1);
+
+    let error = builder.build().unwrap_err();
+    assert_eq!(error.to_string(), "start 5 is after end 1");
+
+    builder.end(10).step(0);
+    let error = builder.build().unwrap_err();
+    assert_eq!(error.to_string(), "step cannot be 0");
+
+    let range = builder.step(2).build().unwrap();
+    assert_eq!((range.start, range.end, range.step), (5, 10, Some(2)));
+
+    // Missing fields are reported before the validation function runs
+    let error = Range::builder().end(1).build().unwrap_err();
+    assert_eq!(error.to_string(), "missing fields: start");
+
+    let mut builder = Range::builder();
+    builder.start(2).end(1);
+    let error = builder.build_owned().unwrap_err();
+    assert_eq!(error.to_string(), "start 2 is after end 1");
+}
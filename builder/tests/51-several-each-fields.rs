@@ -0,0 +1,57 @@
+// Several fields on one struct can each have their own `each` setter. Every
+// setter fills only its own collection, and a collection whose setter is never
+// called is built empty.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    envs: Vec<String>,
+    #[builder(each = "flag")]
+    flags: Vec<char>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .flag('v')
+        .arg("build".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .arg("--release".to_owned())
+        .flag('q')
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.envs, vec!["RUST_LOG=debug"]);
+    assert_eq!(command.flags, vec!['v', 'q']);
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .env("CARGO_TERM_COLOR=always".to_owned())
+        .build()
+        .unwrap();
+
+    assert!(command.args.is_empty());
+    assert_eq!(command.envs, vec!["CARGO_TERM_COLOR=always"]);
+    assert!(command.flags.is_empty());
+
+    // The setters for the whole collections are still there, since none of
+    // them shares a name with its `each` setter
+    let command = Command::builder()
+        .executable("ls".to_owned())
+        .args(vec!["-l".to_owned()])
+        .envs(vec![])
+        .flags(vec!['a'])
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["-l"]);
+    assert!(command.envs.is_empty());
+    assert_eq!(command.flags, vec!['a']);
+}
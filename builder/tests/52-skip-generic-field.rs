@@ -0,0 +1,22 @@
+// A skipped field can be the only place where a type parameter is used, as
+// with a PhantomData marker. The builder is generic over the same parameters,
+// so it has to use them as well, even though it stores no value for the field.
+
+use derive_builder::Builder;
+use std::marker::PhantomData;
+
+pub struct Meters;
+
+#[derive(Builder)]
+pub struct Length<Unit> {
+    #[builder(skip)]
+    unit: PhantomData<Unit>,
+    value: u32,
+}
+
+fn main() {
+    let length: Length<Meters> = Length::builder().value(5).build().unwrap();
+
+    assert_eq!(length.value, 5);
+    let _: PhantomData<Meters> = length.unit;
+}
@@ -0,0 +1,13 @@
+// A skipped field has no setter, so setter options such as `into` have
+// nothing to apply to.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(skip, setter(into))]
+    current_dir: String,
+}
+
+fn main() {}
@@ -10,4 +10,48 @@ fn tests() {
     t.pass("tests/07-repeated-field.rs");
     t.compile_fail("tests/08-unrecognized-attribute.rs");
     t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-generic-struct.rs");
+    t.pass("tests/11-lifetime-parameter.rs");
+    t.pass("tests/12-where-clause.rs");
+    t.pass("tests/13-each-set-and-map.rs");
+    t.pass("tests/14-default-value.rs");
+    t.compile_fail("tests/15-default-with-each.rs");
+    t.pass("tests/16-setter-into.rs");
+    t.pass("tests/17-build-owned.rs");
+    t.pass("tests/18-missing-fields.rs");
+    t.pass("tests/19-builder-default.rs");
+    t.pass("tests/20-builder-name.rs");
+    t.compile_fail("tests/21-invalid-builder-name.rs");
+    t.pass("tests/22-qualified-types.rs");
+    t.pass("tests/23-doc-comments.rs");
+    t.pass("tests/24-visibility.rs");
+    t.pass("tests/25-skip-field.rs");
+    t.compile_fail("tests/26-skip-with-each.rs");
+    t.pass("tests/27-custom-error.rs");
+    t.pass("tests/28-getters.rs");
+    t.pass("tests/29-clearers.rs");
+    t.compile_fail("tests/30-each-conflicts-with-field.rs");
+    t.compile_fail("tests/31-each-conflicts-with-each.rs");
+    t.pass("tests/32-keep-bulk.rs");
+    t.compile_fail("tests/33-keep-bulk-same-name.rs");
+    t.pass("tests/34-raw-identifiers.rs");
+    t.pass("tests/35-const-generics.rs");
+    t.pass("tests/36-requires-exactly-one.rs");
+    t.compile_fail("tests/37-requires-exactly-one-required-field.rs");
+    t.pass("tests/38-owned-setters.rs");
+    t.pass("tests/39-optional-each.rs");
+    t.compile_fail("tests/40-each-unsupported-type.rs");
+    t.pass("tests/41-renamed-functions.rs");
+    t.pass("tests/42-boxed-setter.rs");
+    t.compile_fail("tests/43-boxed-setter-unboxed-field.rs");
+    t.pass("tests/44-no-dead-code-warnings.rs");
+    t.pass("tests/45-tuple-struct.rs");
+    t.pass("tests/46-tuple-struct-missing-fields.rs");
+    t.compile_fail("tests/47-enum.rs");
+    t.pass("tests/48-field-names-matching-locals.rs");
+    t.compile_fail("tests/49-invalid-each-name.rs");
+    t.pass("tests/50-validate.rs");
+    t.pass("tests/51-several-each-fields.rs");
+    t.pass("tests/52-skip-generic-field.rs");
+    t.compile_fail("tests/53-skip-with-setter.rs");
 }
@@ -3,17 +3,19 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::quote;
 use syn::{
+    ext::IdentExt,
     Attribute,
     Data,
-    DataStruct,
     DeriveInput,
     Expr,
     ExprLit,
     Field,
     Fields,
     FieldsNamed,
+    FieldsUnnamed,
     Generics,
     GenericParam,
+    Index,
     Lit,
     Meta,
     MetaNameValue,
@@ -25,25 +27,126 @@ use syn::{
     token::Comma,
     Type::{Path, self},
     TypePath,
-    visit::{self, Visit}, TypeParam, MetaList, parse::{ParseStream, Parse}, Token, LitStr,
+    visit::{self, Visit}, TypeParam, MetaList, parse::{ParseStream, Parse}, Token, LitStr, WherePredicate,
 };
 
-struct CustomBound {
-    bound: String,
+// The contents of a struct attribute such as:
+// #[debug(bound = "T::Value: Debug")]
+// #[debug(transparent)]
+// #[debug(format = "Point({x}, {y})")]
+#[derive(Default)]
+struct StructDebugInfo {
+    bound: Option<LitStr>,
+    // Kept as the ident from the attribute so that errors can point at it
+    transparent: Option<Ident>,
+    format: Option<LitStr>,
 }
 
-impl Parse for CustomBound {
+impl Parse for StructDebugInfo {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let bound_ident: Ident = input.parse()?;
-        if bound_ident != "bound" {
-            return Err(input.error("expected 'bound'"));
+        let mut debug_info = StructDebugInfo::default();
+
+        loop {
+            let key: Ident = input.parse()?;
+
+            if key == "bound" && debug_info.bound.is_none() {
+                let _: Token![=] = input.parse()?;
+                debug_info.bound = Some(input.parse()?);
+            } else if key == "transparent" && debug_info.transparent.is_none() {
+                debug_info.transparent = Some(key);
+            } else if key == "format" && debug_info.format.is_none() {
+                let _: Token![=] = input.parse()?;
+                debug_info.format = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'bound', 'transparent' or 'format'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(debug_info)
+    }
+}
+
+// The contents of a field attribute such as:
+// #[debug(skip)]
+// #[debug(format_with = "fmt_hex")]
+// #[debug(name = "display_name")]
+// #[debug("{}+{}", x, y)]
+#[derive(Default)]
+struct FieldDebugInfo {
+    skip: bool,
+    format_with: Option<LitStr>,
+    name: Option<LitStr>,
+    format: Option<LitStr>,
+    format_args: Vec<Ident>,
+}
+
+impl Parse for FieldDebugInfo {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut debug_info = FieldDebugInfo::default();
+
+        // A format string followed by the fields to format with it takes up the whole attribute
+        if input.peek(LitStr) {
+            debug_info.format = Some(input.parse()?);
+
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+
+            let format_args = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+            debug_info.format_args = format_args.into_iter().collect();
+
+            return Ok(debug_info);
+        }
+
+        loop {
+            let key: Ident = input.parse()?;
+
+            if key == "skip" && !debug_info.skip {
+                debug_info.skip = true;
+            } else if key == "format_with" && debug_info.format_with.is_none() {
+                let _: Token![=] = input.parse()?;
+                debug_info.format_with = Some(input.parse()?);
+            } else if key == "name" && debug_info.name.is_none() {
+                let _: Token![=] = input.parse()?;
+                debug_info.name = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'skip', 'format_with' or 'name'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
         }
 
-        let _: Token![=] = input.parse()?;
+        Ok(debug_info)
+    }
+}
 
-        let bound: LitStr = input.parse()?;
+// Everything that the `debug` attributes on a field say about how to format it.
+#[derive(Default)]
+struct FieldOptions {
+    custom_format: Option<String>,
+    // The fields to format with the custom format, if not just the field itself.
+    custom_format_args: Vec<Ident>,
+    // A function `fn(&T, &mut Formatter) -> fmt::Result` that formats the field instead of its
+    // own Debug impl.
+    format_with: Option<syn::Path>,
+    // The label to print for the field in place of its name.
+    name: Option<String>,
+    skip: bool,
+}
 
-        Ok(CustomBound { bound: bound.value() })
+impl FieldOptions {
+    fn is_empty(&self) -> bool {
+        !self.skip && self.custom_format.is_none() && self.format_with.is_none() && self.name.is_none()
     }
 }
 
@@ -66,18 +169,42 @@ fn custom_format_from_debug_attribute(attr: &Attribute) -> syn::Result<Option<St
     }
 }
 
+// Parses an attribute such as:
+// #[debug(skip)]
+// #[debug(format_with = "fmt_hex")]
+// #[debug(name = "display_name")]
+// #[debug("{}+{}", x, y)]
+fn field_debug_info_from_debug_attribute(attr: &Attribute) -> syn::Result<FieldDebugInfo> {
+    if_chain! {
+        if let Attribute { meta, .. } = attr;
+        if let Meta::List(meta) = meta;
+        let MetaList { path, tokens, .. } = meta;
+        if path.is_ident("debug");
+        then {
+            match syn::parse2::<FieldDebugInfo>(tokens.clone()) {
+                Ok(debug_info) => Ok(debug_info),
+                Err(_) => Err(syn::Error::new_spanned(&attr.meta, "expected `debug(skip)`, `debug(format_with = \"...\")`, `debug(name = \"...\")` or `debug(\"...\", fields...)`")),
+            }
+        } else {
+            Err(syn::Error::new_spanned(&attr.meta, "expected `debug(skip)`, `debug(format_with = \"...\")`, `debug(name = \"...\")` or `debug(\"...\", fields...)`"))
+        }
+    }
+}
+
 // Parses an attribute such as:
 // #[debug(bound = "T::Value: Debug")]
-fn custom_bound_from_debug_attribute(attr: &Attribute) -> syn::Result<Option<String>> {
+// #[debug(transparent)]
+// #[debug(format = "Point({x}, {y})")]
+fn struct_debug_info_from_debug_attribute(attr: &Attribute) -> syn::Result<Option<StructDebugInfo>> {
     if_chain! {
         if let Attribute { meta, .. } = attr;
         if let Meta::List(meta) = meta;
         let MetaList { path, tokens, .. } = meta;
         if path.is_ident("debug");
         then {
-            match syn::parse2::<CustomBound>(tokens.clone()) {
-                Ok(custom_bound) => Ok(Some(custom_bound.bound)),
-                Err(_) => Err(syn::Error::new_spanned(&attr.meta, "expected `debug(bound = \"...\")`")),
+            match syn::parse2::<StructDebugInfo>(tokens.clone()) {
+                Ok(debug_info) => Ok(Some(debug_info)),
+                Err(_) => Err(syn::Error::new_spanned(&attr.meta, "expected `debug(bound = \"...\")`, `debug(transparent)` or `debug(format = \"...\")`")),
             }
         } else {
             // Unlike field attributes, we may see attributes here that are unrelated to this macro
@@ -86,38 +213,235 @@ fn custom_bound_from_debug_attribute(attr: &Attribute) -> syn::Result<Option<Str
     }
 }
 
-fn custom_format_from_field_attributes(attrs: &Vec<Attribute>) -> syn::Result<Option<String>> {
-    let mut custom_format: Option<_> = None;
+fn field_options_from_field_attributes(attrs: &Vec<Attribute>) -> syn::Result<FieldOptions> {
+    let mut field_options = FieldOptions::default();
 
     for attr in attrs {
-        let this_custom_format = custom_format_from_debug_attribute(attr)?;
-        if this_custom_format.is_some() {
-            if custom_format.is_some() {
-                return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug' custom format attribute should be specified"));
-            } else {
-                custom_format = this_custom_format;
+        if let Meta::List(_) = &attr.meta {
+            let debug_info = field_debug_info_from_debug_attribute(attr)?;
+            if let Some(format) = debug_info.format {
+                if field_options.custom_format.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug' custom format attribute should be specified"));
+                }
+                field_options.custom_format = Some(format.value());
+                field_options.custom_format_args = debug_info.format_args;
+            }
+
+            if debug_info.skip {
+                if field_options.skip {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug(skip)' attribute should be specified"));
+                }
+                field_options.skip = true;
+            }
+
+            if let Some(format_with) = debug_info.format_with {
+                if field_options.format_with.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug(format_with = ...)' attribute should be specified"));
+                }
+
+                match format_with.parse::<syn::Path>() {
+                    Ok(format_with) => field_options.format_with = Some(format_with),
+                    Err(_) => return Err(syn::Error::new_spanned(&format_with, "expected `format_with` to be the path of a function")),
+                }
+            }
+
+            if let Some(name) = debug_info.name {
+                if field_options.name.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug(name = ...)' attribute should be specified"));
+                }
+                field_options.name = Some(name.value());
             }
+        } else {
+            let this_custom_format = custom_format_from_debug_attribute(attr)?;
+            if this_custom_format.is_some() {
+                if field_options.custom_format.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug' custom format attribute should be specified"));
+                } else {
+                    field_options.custom_format = this_custom_format;
+                }
+            }
+        }
+
+        if field_options.skip && field_options.custom_format.is_some() {
+            return Err(syn::Error::new_spanned(&attr.meta, "a skipped field cannot also have a 'debug' custom format"));
+        }
+
+        if field_options.skip && field_options.format_with.is_some() {
+            return Err(syn::Error::new_spanned(&attr.meta, "a skipped field cannot also have 'debug(format_with = ...)'"));
+        }
+
+        if field_options.skip && field_options.name.is_some() {
+            return Err(syn::Error::new_spanned(&attr.meta, "a skipped field cannot also have 'debug(name = ...)'"));
+        }
+
+        if field_options.custom_format.is_some() && field_options.format_with.is_some() {
+            return Err(syn::Error::new_spanned(&attr.meta, "a field cannot have both a 'debug' custom format and 'debug(format_with = ...)'"));
         }
     }
-    
-    Ok(custom_format)
+
+    Ok(field_options)
 }
 
-fn custom_bounds_from_struct_attributes(attrs: &Vec<Attribute>) -> syn::Result<Option<String>> {
-    let mut custom_bound: Option<_> = None;
+fn struct_options_from_struct_attributes(attrs: &Vec<Attribute>) -> syn::Result<StructDebugInfo> {
+    let mut struct_options = StructDebugInfo::default();
 
     for attr in attrs {
-        let this_custom_bound = custom_bound_from_debug_attribute(attr)?;
-        if this_custom_bound.is_some() {
-            if custom_bound.is_some() {
-                return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug' custom bound attribute should be specified"));
-            } else {
-                custom_bound = this_custom_bound;
+        if let Some(debug_info) = struct_debug_info_from_debug_attribute(attr)? {
+            if debug_info.bound.is_some() {
+                if struct_options.bound.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug' custom bound attribute should be specified"));
+                }
+                struct_options.bound = debug_info.bound;
+            }
+
+            if debug_info.transparent.is_some() {
+                if struct_options.transparent.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug(transparent)' attribute should be specified"));
+                }
+                struct_options.transparent = debug_info.transparent;
+            }
+
+            if debug_info.format.is_some() {
+                if struct_options.format.is_some() {
+                    return Err(syn::Error::new_spanned(&attr.meta, "only one 'debug(format = ...)' attribute should be specified"));
+                }
+                struct_options.format = debug_info.format;
+            }
+
+            if struct_options.transparent.is_some() && struct_options.format.is_some() {
+                return Err(syn::Error::new_spanned(&attr.meta, "`debug(transparent)` and `debug(format = ...)` cannot be used together"));
+            }
+        }
+    }
+
+    Ok(struct_options)
+}
+
+// Produces the version of a custom format string to use when formatting with `{:#?}`, so that
+// values formatted with `{:?}` inside it are pretty-printed along with the rest of the struct.
+// For example, "at {:?}" becomes "at {:#?}". Any other format specifiers are left alone.
+fn alternate_format_string(format: &str) -> String {
+    let mut alternate_format = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        alternate_format.push(c);
+
+        // Escaped braces are copied as they are
+        if (c == '{' || c == '}') && chars.peek() == Some(&c) {
+            alternate_format.push(chars.next().unwrap());
+            continue;
+        }
+
+        if c != '{' {
+            continue;
+        }
+
+        let mut placeholder = String::new();
+        for c in chars.by_ref() {
+            if c == '}' {
+                break;
+            }
+            placeholder.push(c);
+        }
+
+        if let Some((argument, spec)) = placeholder.split_once(':') {
+            alternate_format.push_str(argument);
+            alternate_format.push(':');
+            alternate_format.push_str(&alternate_format_spec(spec));
+        } else {
+            alternate_format.push_str(&placeholder);
+        }
+
+        alternate_format.push('}');
+    }
+
+    alternate_format
+}
+
+// Adds the `#` flag to a Debug format spec such as "?" or ">10?", which comes after any fill,
+// alignment and sign.
+fn alternate_format_spec(spec: &str) -> String {
+    if !spec.ends_with('?') {
+        return spec.to_owned();
+    }
+
+    let chars: Vec<char> = spec.chars().collect();
+    let is_align = |c: Option<&char>| matches!(c, Some('<' | '^' | '>'));
+
+    let mut flag_index =
+        if is_align(chars.get(1)) {
+            2
+        } else if is_align(chars.first()) {
+            1
+        } else {
+            0
+        };
+
+    if matches!(chars.get(flag_index), Some('+' | '-')) {
+        flag_index += 1;
+    }
+
+    if chars.get(flag_index) == Some(&'#') {
+        return spec.to_owned();
+    }
+
+    let mut alternate_spec: String = chars[..flag_index].iter().collect();
+    alternate_spec.push('#');
+    alternate_spec.extend(&chars[flag_index..]);
+    alternate_spec
+}
+
+// Whether a type is PhantomData, written either as a bare name or as a path into `std::marker`
+// or `core::marker`.
+fn is_phantom_data(ty: &Type) -> bool {
+    if_chain! {
+        if let Path(TypePath { qself: None, path }) = ty;
+        if let Some(PathSegment { ident, .. }) = path.segments.last();
+        if ident == "PhantomData";
+        then {
+            let leading_idents: Vec<String> = path.segments
+                .iter()
+                .take(path.segments.len() - 1)
+                .map(|segment| segment.ident.to_string())
+                .collect();
+
+            match leading_idents.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+                [] => path.leading_colon.is_none(),
+                ["std" | "core", "marker"] => true,
+                _ => false,
             }
+        } else {
+            false
         }
     }
-    
-    Ok(custom_bound)
+}
+
+// A visitor that finds whether a type parameter is used by itself, outside of PhantomData
+struct TypeParamUseVisitor<'a> {
+    ident: &'a Ident,
+    used: bool,
+}
+
+impl<'ast> Visit<'ast> for TypeParamUseVisitor<'_> {
+    fn visit_type(&mut self, ty: &'ast Type) {
+        if is_phantom_data(ty) {
+            return;
+        }
+
+        if_chain! {
+            if let Path(TypePath { qself: None, path }) = ty;
+            if path.leading_colon.is_none() && path.segments.len() == 1;
+            if let Some(PathSegment { ident, arguments: PathArguments::None }) = path.segments.first();
+            if ident == self.ident;
+            then {
+                self.used = true;
+                return;
+            }
+        }
+
+        visit::visit_type(self, ty);
+    }
 }
 
 // A visitor that enumerates any types that use a certain set of generic type parameters
@@ -137,15 +461,24 @@ impl<'ast> TypeParamVisitor<'ast> {
 
 impl<'ast> Visit<'ast> for TypeParamVisitor<'ast> {
     fn visit_type(&mut self, ty: &'ast Type) {
+        // PhantomData implements Debug whatever its type parameter is, so nothing inside it needs
+        // a bound.
+        if is_phantom_data(ty) {
+            return;
+        }
+
         if_chain! {
             if let Path(TypePath { qself: None, path: syn::Path { segments, leading_colon: None } }) = ty;
             if segments.len() > 1;
             if let Some(PathSegment { ident, arguments: PathArguments::None }) = segments.first();
             if self.type_params.iter().find(|type_param| type_param.ident == *ident).is_some();
+            // The same associated type is often used by several fields, but needs only one bound
+            if !self.related_types.iter().any(|related_type| quote!(#related_type).to_string() == quote!(#ty).to_string());
             then { self.related_types.push(ty.clone()) }
         }
 
-        // Delegate to the default impl so that we get type parameters as well.
+        // Delegate to the default impl so that we get type parameters as well, including
+        // associated types nested inside other types such as `Vec<T::Value>`.
         visit::visit_type(self, ty);
     }
 }
@@ -157,33 +490,45 @@ pub fn derive(input: TokenStream) -> TokenStream {
     if_chain! {
         if let DeriveInput { ident: struct_name, generics, data, attrs, .. } = &derive_input;
         if let Data::Struct(data_struct) = data;
-        if let DataStruct { fields, .. } = data_struct;
-        if let Fields::Named(fields) = fields;
-        if let FieldsNamed { named: fields, .. } = fields;
         then {
-            let debug_struct_fields: proc_macro2::TokenStream = fields.iter().map(|field| {
-                if let Field { ident: Some(field_name), attrs, .. } = &field {
-                    let field_name_string = field_name.to_string();
-
-                    let custom_format = match custom_format_from_field_attributes(attrs) {
-                        Ok(custom_format) => custom_format,
-                        Err(error) => {
-                            return error.to_compile_error().into();
-                        }
-                    };
+            let struct_options = match struct_options_from_struct_attributes(attrs) {
+                Ok(struct_options) => struct_options,
+                Err(error) => {
+                    return error.to_compile_error().into();
+                }
+            };
 
-                    let format = match custom_format {
-                        Some(custom_format) => quote! { &format_args!(#custom_format, &self.#field_name) },
-                        None => quote! { &self.#field_name },
-                    };
+            let no_fields = Punctuated::new();
 
-                    quote! {
-                        .field(#field_name_string, #format)
-                    }
-                } else {
-                    quote! {}
+            let fields = match &data_struct.fields {
+                Fields::Named(FieldsNamed { named: fields, .. }) => fields,
+                Fields::Unnamed(FieldsUnnamed { unnamed: fields, .. }) if struct_options.transparent.is_some() => fields,
+                Fields::Unit => &no_fields,
+                Fields::Unnamed(_) => {
+                    return syn::Error::new_spanned(struct_name, "CustomDebug can only be derived for tuple structs with `debug(transparent)`")
+                        .to_compile_error()
+                        .into();
+                },
+            };
+
+            let fmt_body = match (&struct_options.transparent, &data_struct.fields) {
+                (Some(transparent), _) => transparent_fmt_body(transparent, fields),
+                (None, _) if struct_options.format.is_some() => container_format_fmt_body(struct_options.format.as_ref().unwrap(), fields),
+                // A unit struct prints just its name, like the standard library's derived Debug
+                (None, Fields::Unit) => {
+                    let struct_name_string = struct_name.to_string();
+                    Ok(quote! { fmt.write_str(#struct_name_string) })
+                },
+                (None, _) => debug_struct_fmt_body(struct_name, fields),
+            };
+
+            // Attribute errors can't be reported from inside the generated `fmt` body
+            let fmt_body = match fmt_body {
+                Ok(fmt_body) => fmt_body,
+                Err(error) => {
+                    return error.to_compile_error().into();
                 }
-            }).collect();
+            };
 
             let struct_type_parameters: Vec<_> = generics.params
                 .iter()
@@ -197,50 +542,270 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 .collect();
 
             let mut type_param_visitor = TypeParamVisitor::new(struct_type_parameters);
-            type_param_visitor.visit_data_struct(&data_struct);
-
-            let mut associated_type_bounds: Vec<_> = type_param_visitor.related_types
-                .iter()
-                .map(|ty| {
-                    quote!(#ty : Debug)
-                })
-                .collect();
+            type_param_visitor.visit_data_struct(data_struct);
 
-            let custom_bound = match custom_bounds_from_struct_attributes(attrs) {
-                Ok(custom_bound) => custom_bound,
-                Err(error) => {
-                    return error.to_compile_error().into();
-                }
+            let custom_bound_predicates = match struct_options.bound {
+                Some(custom_bound) => match custom_bound.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated) {
+                    Ok(predicates) => predicates,
+                    Err(_) => {
+                        return syn::Error::new_spanned(&custom_bound, "expected `bound` to be a list of where-clause predicates")
+                            .to_compile_error()
+                            .into();
+                    }
+                },
+                None => Punctuated::new(),
             };
 
-            if let Some(custom_bound) = custom_bound {
-                associated_type_bounds.push(custom_bound.parse().unwrap());
-            }
+            // A custom bound replaces all of the inferred bounds, since it is only needed when the
+            // inference gets them wrong.
+            let use_inferred_bounds = custom_bound_predicates.is_empty();
 
-            let where_clauses =
-                if associated_type_bounds.len() > 0 {
-                    quote!(where #(#associated_type_bounds)*)
+            let mut generics =
+                if use_inferred_bounds {
+                    add_trait_bounds(generics.clone(), fields)
                 } else {
-                    quote!()
+                    generics.clone()
                 };
 
-            let struct_name_string = struct_name.to_string();
+            // The bounds on associated types are added to the struct's own where-clause, if it
+            // has one, so that its predicates still apply to the impl.
+            let where_clause = generics.make_where_clause();
 
-            let generics = add_trait_bounds(generics.clone(), &fields);
-            let (impl_generics, struct_generics, _) = generics.split_for_impl();
+            if use_inferred_bounds {
+                for ty in &type_param_visitor.related_types {
+                    where_clause.predicates.push(parse_quote!(#ty: std::fmt::Debug));
+                }
+            }
+
+            where_clause.predicates.extend(custom_bound_predicates);
+
+            let (impl_generics, struct_generics, where_clause) = generics.split_for_impl();
 
             TokenStream::from(quote! {
                 impl #impl_generics std::fmt::Debug for #struct_name #struct_generics
-                    #where_clauses {
+                    #where_clause {
                     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        fmt.debug_struct(#struct_name_string)
-                            #debug_struct_fields
-                            .finish()
+                        #fmt_body
                     }
                 }
             })
         }
-        else { TokenStream::new() }
+        else {
+            syn::Error::new_spanned(&derive_input.ident, "CustomDebug can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    }
+}
+
+// Generates the body of `fmt` for a struct with named fields, using `debug_struct`.
+fn debug_struct_fmt_body(struct_name: &Ident, fields: &Punctuated<Field, Comma>) -> syn::Result<proc_macro2::TokenStream> {
+    let mut uses_format_with = false;
+    let mut uses_alternate = false;
+
+    let debug_struct_fields = fields.iter().map(|field| {
+        if let Field { ident: Some(field_name), attrs, .. } = &field {
+            let field_options = field_options_from_field_attributes(attrs)?;
+
+            let field_name_string = match &field_options.name {
+                Some(name) => name.clone(),
+                None => field_name.unraw().to_string(),
+            };
+
+            if field_options.skip {
+                return Ok(quote! {});
+            }
+
+            let format = match (field_options.custom_format, field_options.format_with) {
+                (Some(custom_format), _) => {
+                    let format_args =
+                        if field_options.custom_format_args.is_empty() {
+                            vec![field_name.clone()]
+                        } else {
+                            field_options.custom_format_args
+                        };
+
+                    for format_arg in &format_args {
+                        if !fields.iter().any(|field| field.ident.as_ref().is_some_and(|other_field_name| other_field_name.unraw() == format_arg.unraw())) {
+                            return Err(syn::Error::new(format_arg.span(), format!("no field named `{}` to use in the custom format", format_arg.unraw())));
+                        }
+                    }
+
+                    let alternate_format = alternate_format_string(&custom_format);
+                    if alternate_format != custom_format {
+                        uses_alternate = true;
+                        return Ok(quote! {
+                            if alternate {
+                                debug_struct.field(#field_name_string, &format_args!(#alternate_format, #(&self.#format_args),*));
+                            } else {
+                                debug_struct.field(#field_name_string, &format_args!(#custom_format, #(&self.#format_args),*));
+                            }
+                        });
+                    }
+
+                    quote! { &format_args!(#custom_format, #(&self.#format_args),*) }
+                },
+                (None, Some(format_with)) => {
+                    uses_format_with = true;
+                    quote! { &FormatWith { value: &self.#field_name, fmt: #format_with } }
+                },
+                (None, None) => quote! { &self.#field_name },
+            };
+
+            Ok(quote! {
+                debug_struct.field(#field_name_string, #format);
+            })
+        } else {
+            Ok(quote! {})
+        }
+    }).collect::<syn::Result<proc_macro2::TokenStream>>()?;
+
+    // Adapts a `format_with` function into something that `debug_struct` can format
+    let format_with_adapter =
+        if uses_format_with {
+            quote! {
+                struct FormatWith<'a, T: ?Sized> {
+                    value: &'a T,
+                    fmt: fn(&T, &mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+                }
+
+                impl<'a, T: ?Sized> std::fmt::Debug for FormatWith<'a, T> {
+                    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        (self.fmt)(self.value, fmt)
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+
+    // The flag has to be read before `debug_struct` borrows the formatter
+    let read_alternate =
+        if uses_alternate {
+            quote! { let alternate = fmt.alternate(); }
+        } else {
+            quote!()
+        };
+
+    let struct_name_string = struct_name.to_string();
+
+    Ok(quote! {
+        #format_with_adapter
+
+        #read_alternate
+
+        let mut debug_struct = fmt.debug_struct(#struct_name_string);
+        #debug_struct_fields
+        debug_struct.finish()
+    })
+}
+
+// Generates the body of `fmt` for a `debug(transparent)` struct, which forwards to its only field.
+fn transparent_fmt_body(transparent: &Ident, fields: &Punctuated<Field, Comma>) -> syn::Result<proc_macro2::TokenStream> {
+    if fields.len() != 1 {
+        return Err(syn::Error::new(transparent.span(), "`debug(transparent)` can only be used on a struct with exactly one field"));
+    }
+
+    let field = fields.first().unwrap();
+
+    // Formatting attributes would have no effect, since the field is printed exactly as it is
+    if !field_options_from_field_attributes(&field.attrs)?.is_empty() {
+        return Err(syn::Error::new(transparent.span(), "the field of a `debug(transparent)` struct cannot have `debug` attributes"));
+    }
+
+    let member = match &field.ident {
+        Some(field_name) => quote! { #field_name },
+        None => {
+            let index = Index::from(0);
+            quote! { #index }
+        },
+    };
+
+    Ok(quote! {
+        std::fmt::Debug::fmt(&self.#member, fmt)
+    })
+}
+
+// Generates the body of `fmt` for a struct with a `debug(format = "...")` attribute. Each `{field}`
+// placeholder in the format string is replaced by that field's Debug output, or formatted with
+// the given spec in the case of `{field:spec}`.
+fn container_format_fmt_body(format: &LitStr, fields: &Punctuated<Field, Comma>) -> syn::Result<proc_macro2::TokenStream> {
+    for field in fields {
+        if !field_options_from_field_attributes(&field.attrs)?.is_empty() {
+            return Err(syn::Error::new_spanned(format, "the fields of a struct with `debug(format = ...)` cannot have `debug` attributes"));
+        }
+    }
+
+    // Placeholders are rewritten to refer to positional arguments, one for each field that the
+    // format string uses, so that field names never need to be valid format argument names.
+    let format_value = format.value();
+    let mut positional_format = String::with_capacity(format_value.len());
+    let mut field_names: Vec<&Ident> = Vec::new();
+    let mut chars = format_value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        positional_format.push(c);
+
+        // Escaped braces are copied as they are
+        if (c == '{' || c == '}') && chars.peek() == Some(&c) {
+            positional_format.push(chars.next().unwrap());
+            continue;
+        }
+
+        if c != '{' {
+            continue;
+        }
+
+        let mut placeholder = String::new();
+        for c in chars.by_ref() {
+            if c == '}' {
+                break;
+            }
+            placeholder.push(c);
+        }
+
+        let (placeholder_name, spec) = placeholder.split_once(':').unwrap_or((&placeholder, "?"));
+
+        let field_name = fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .find(|field_name| field_name.unraw() == placeholder_name.trim());
+
+        let field_name = match field_name {
+            Some(field_name) => field_name,
+            None if placeholder_name.trim().is_empty() => {
+                return Err(syn::Error::new_spanned(format, "each placeholder in `debug(format = ...)` must name a field, as in `{x}`"));
+            },
+            None => {
+                return Err(syn::Error::new_spanned(format, format!("no field named `{}` to use in `debug(format = ...)`", placeholder_name.trim())));
+            },
+        };
+
+        let index = match field_names.iter().position(|&existing| existing == field_name) {
+            Some(index) => index,
+            None => {
+                field_names.push(field_name);
+                field_names.len() - 1
+            },
+        };
+
+        positional_format.push_str(&format!("{}:{}}}", index, spec));
+    }
+
+    let alternate_format = alternate_format_string(&positional_format);
+
+    if alternate_format != positional_format {
+        Ok(quote! {
+            if fmt.alternate() {
+                std::write!(fmt, #alternate_format, #(&self.#field_names),*)
+            } else {
+                std::write!(fmt, #positional_format, #(&self.#field_names),*)
+            }
+        })
+    } else {
+        Ok(quote! {
+            std::write!(fmt, #positional_format, #(&self.#field_names),*)
+        })
     }
 }
 
@@ -250,23 +815,14 @@ fn add_trait_bounds(mut generics: Generics, fields: &Punctuated<Field, Comma>) -
             // Special case for PhantomData, which is very common and which implements Debug
             // regardless of its type parameters.
             //
-            // Only add the trait bound if this type parameter is used outside a PhantomData field.
-            let used_outside_phantom_data = fields.iter().find(|&f| {
-                if_chain! {
-                    if let Path(path) = &f.ty;
-                    if let TypePath { qself: None, path } = path;
-                    if let syn::Path { segments, leading_colon: None } = path;
-                    if segments.len() == 1;
-                    if let Some(segment) = segments.first();
-                    if let PathSegment { ident, arguments: PathArguments::None } = segment;
-                    if *ident == type_param.ident;
-                    then {
-                        true
-                    } else {
-                        false
-                    }
-                }
-            }).is_some();
+            // Only add the trait bound if this type parameter is used outside a PhantomData field,
+            // either on its own or inside another type such as an array `[T; N]`. Associated types
+            // such as `T::Value` are bounded separately.
+            let mut type_param_use_visitor = TypeParamUseVisitor { ident: &type_param.ident, used: false };
+            for field in fields {
+                type_param_use_visitor.visit_type(&field.ty);
+            }
+            let used_outside_phantom_data = type_param_use_visitor.used;
 
             if used_outside_phantom_data {
                 type_param.bounds.push(parse_quote!(std::fmt::Debug));
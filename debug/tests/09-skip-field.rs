@@ -0,0 +1,27 @@
+// A field marked #[debug(skip)] is left out of the debug output entirely. This
+// is useful for secrets, or for large buffers that would drown out the rest of
+// the struct.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Connection {
+    host: &'static str,
+    #[debug(skip)]
+    password: &'static str,
+    port: u16,
+}
+
+fn main() {
+    let connection = Connection {
+        host: "localhost",
+        password: "hunter2",
+        port: 5432,
+    };
+
+    let debug = format!("{:?}", connection);
+    let expected = r#"Connection { host: "localhost", port: 5432 }"#;
+
+    assert_eq!(debug, expected);
+    assert!(!debug.contains("hunter2"));
+}
@@ -0,0 +1,14 @@
+// A skipped field is never formatted, so giving it a custom format string as
+// well is a mistake that should be reported.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Connection {
+    host: &'static str,
+    #[debug(skip)]
+    #[debug = "{:?}"]
+    password: &'static str,
+}
+
+fn main() {}
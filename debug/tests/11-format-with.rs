@@ -0,0 +1,44 @@
+// A field attribute #[debug(format_with = "...")] names a function with the
+// signature `fn(&T, &mut Formatter) -> fmt::Result` that formats the field in
+// place of its own Debug impl. The path may refer to any function in scope,
+// including one in another module.
+
+use derive_debug::CustomDebug;
+use std::fmt;
+
+mod hex {
+    use std::fmt;
+
+    pub fn bytes(bytes: &Vec<u8>, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for byte in bytes {
+            write!(fmt, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+fn redacted(_: &&'static str, fmt: &mut fmt::Formatter) -> fmt::Result {
+    fmt.write_str("<redacted>")
+}
+
+#[derive(CustomDebug)]
+pub struct Packet {
+    id: u32,
+    #[debug(format_with = "hex::bytes")]
+    payload: Vec<u8>,
+    #[debug(format_with = "redacted")]
+    token: &'static str,
+}
+
+fn main() {
+    let packet = Packet {
+        id: 7,
+        payload: vec![0xde, 0xad, 0xbe, 0xef],
+        token: "secret",
+    };
+
+    let debug = format!("{:?}", packet);
+    let expected = "Packet { id: 7, payload: deadbeef, token: <redacted> }";
+
+    assert_eq!(debug, expected);
+}
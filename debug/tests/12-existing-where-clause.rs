@@ -0,0 +1,41 @@
+// A struct may already have a where-clause of its own. Its predicates have to be
+// kept on the generated impl, with the inferred bounds on associated types
+// added to the same clause:
+//
+//     impl<T> Debug for Field<T>
+//     where
+//         T: Trait + Clone,
+//         T::Value: Debug,
+//     {...}
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub struct Field<T>
+where
+    T: Trait + Clone,
+{
+    values: Vec<T::Value>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug, but its associated type does.
+    #[derive(Clone)]
+    struct Id;
+
+    impl Trait for Id {
+        type Value = u8;
+    }
+
+    assert_debug::<Field<Id>>();
+
+    let field = Field::<Id> { values: vec![1, 2] };
+    assert_eq!(format!("{:?}", field), "Field { values: [1, 2] }");
+}
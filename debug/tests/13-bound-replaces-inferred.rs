@@ -0,0 +1,40 @@
+// The bounds given in #[debug(bound = "...")] replace every inferred bound,
+// rather than being added alongside them.
+//
+// Here the `cache` field is skipped, so the inferred `T::Value: Debug` bound is
+// unnecessary and would stop `Cached<Id>` from implementing Debug. The manual
+// bound asks only for what the remaining fields need.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "T: Debug")]
+pub struct Cached<T: Trait> {
+    key: T,
+    #[debug(skip)]
+    cache: Vec<T::Value>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    #[derive(Debug)]
+    struct Id;
+
+    // Does not implement Debug.
+    struct Opaque;
+
+    impl Trait for Id {
+        type Value = Opaque;
+    }
+
+    assert_debug::<Cached<Id>>();
+
+    let cached = Cached { key: Id, cache: vec![Opaque] };
+    assert_eq!(format!("{:?}", cached), "Cached { key: Id }");
+}
@@ -0,0 +1,45 @@
+// Formatting with `{:#?}` prints one field per line, indented, just like the
+// standard library's derived Debug. Fields with a custom format string take
+// part as well: any `{:?}` in the format string is pretty-printed when the
+// struct is, while other format specifiers are left as they are.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(CustomDebug)]
+pub struct Shape {
+    name: &'static str,
+    #[debug = "at {:?}"]
+    origin: Point,
+    #[debug = "0b{:08b}"]
+    flags: u8,
+}
+
+fn main() {
+    let shape = Shape {
+        name: "square",
+        origin: Point { x: 1, y: 2 },
+        flags: 0b101,
+    };
+
+    let debug = format!("{:?}", shape);
+    let expected = r#"Shape { name: "square", origin: at Point { x: 1, y: 2 }, flags: 0b00000101 }"#;
+    assert_eq!(debug, expected);
+
+    let debug = format!("{:#?}", shape);
+    let expected = r#"Shape {
+    name: "square",
+    origin: at Point {
+        x: 1,
+        y: 2,
+    },
+    flags: 0b00000101,
+}"#;
+    assert!(debug.contains('\n'));
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,27 @@
+// A field attribute #[debug(name = "...")] prints the field under a different
+// label than its Rust name. It can be combined with a custom format.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Request {
+    #[debug(name = "request-id")]
+    id: u64,
+    #[debug(name = "bitmask")]
+    #[debug = "0b{:04b}"]
+    flags: u8,
+    path: &'static str,
+}
+
+fn main() {
+    let request = Request {
+        id: 42,
+        flags: 0b1010,
+        path: "/",
+    };
+
+    let debug = format!("{:?}", request);
+    let expected = r#"Request { request-id: 42, bitmask: 0b1010, path: "/" }"#;
+
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,14 @@
+// A skipped field is never printed, so giving it a new name is a mistake that
+// should be reported.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Request {
+    id: u64,
+    #[debug(skip)]
+    #[debug(name = "secret")]
+    token: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// A struct attribute #[debug(transparent)] makes a struct with exactly one
+// field print exactly like that field, with no struct name or field label
+// around it. This suits newtype wrappers, whether the field is named or not.
+// The usual bound inference still applies to the field's type.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct Meters(f64);
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct UserId {
+    id: u64,
+}
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct Wrapper<T>(T);
+
+fn main() {
+    assert_eq!(format!("{:?}", Meters(1.5)), "1.5");
+    assert_eq!(format!("{:?}", UserId { id: 7 }), "7");
+    assert_eq!(format!("{:?}", Wrapper("a")), r#""a""#);
+    assert_eq!(format!("{:#?}", Wrapper(vec![1])), "[\n    1,\n]");
+}
@@ -0,0 +1,13 @@
+// A struct with more than one field has no single field to forward to, so
+// #[debug(transparent)] is rejected.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
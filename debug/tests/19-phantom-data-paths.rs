@@ -0,0 +1,44 @@
+// Nothing inside a PhantomData needs to implement Debug, however the
+// PhantomData is spelled and whatever it contains. In particular, associated
+// types mentioned only inside a PhantomData must not get an inferred
+// `T::Value: Debug` bound.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub struct Field<T: Trait> {
+    id: u8,
+    marker: std::marker::PhantomData<T>,
+    value_marker: PhantomData<T::Value>,
+    values_marker: core::marker::PhantomData<Vec<T::Value>>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Neither the type nor its associated type implements Debug.
+    struct Id;
+    struct Opaque;
+
+    impl Trait for Id {
+        type Value = Opaque;
+    }
+
+    assert_debug::<Field<Id>>();
+
+    let field = Field::<Id> {
+        id: 1,
+        marker: PhantomData,
+        value_marker: PhantomData,
+        values_marker: PhantomData,
+    };
+
+    let debug = format!("{:?}", field);
+    assert!(debug.starts_with("Field { id: 1, marker: PhantomData<"));
+}
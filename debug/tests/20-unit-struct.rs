@@ -0,0 +1,17 @@
+// A unit struct has no fields to show, so its debug output is just its name.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Marker;
+
+#[derive(CustomDebug)]
+pub struct Holder {
+    marker: Marker,
+}
+
+fn main() {
+    assert_eq!(format!("{:?}", Marker), "Marker");
+    assert_eq!(format!("{:#?}", Marker), "Marker");
+    assert_eq!(format!("{:?}", Holder { marker: Marker }), "Holder { marker: Marker }");
+}
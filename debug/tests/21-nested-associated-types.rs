@@ -0,0 +1,50 @@
+// Associated types get an inferred Debug bound wherever they appear in a field
+// type, including nested inside other generic types, tuples, arrays and
+// references. An associated type used by several fields is bounded only once.
+
+use derive_debug::CustomDebug;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+    type Item;
+}
+
+#[derive(CustomDebug)]
+pub struct Field<'a, T: Trait> {
+    items: Vec<T::Value>,
+    first: Option<T::Item>,
+    by_name: HashMap<String, Vec<Option<T::Value>>>,
+    pair: (u8, T::Item),
+    array: [T::Value; 2],
+    borrowed: &'a T::Item,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug, but its associated types do.
+    struct Id;
+
+    impl Trait for Id {
+        type Value = u8;
+        type Item = bool;
+    }
+
+    assert_debug::<Field<Id>>();
+
+    let field = Field::<Id> {
+        items: vec![1],
+        first: Some(true),
+        by_name: HashMap::new(),
+        pair: (2, false),
+        array: [3, 4],
+        borrowed: &true,
+    };
+
+    let debug = format!("{:?}", field);
+    let expected = "Field { items: [1], first: Some(true), by_name: {}, pair: (2, false), array: [3, 4], borrowed: true }";
+
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,41 @@
+// A struct attribute #[debug(format = "...")] replaces the usual field-by-field
+// output with a custom string. Each `{field}` placeholder is filled in with
+// that field's Debug output, and `{field:spec}` formats the field with the
+// given spec instead. A field may be used more than once, or not at all.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(format = "Point({x}, {y})")]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(CustomDebug)]
+#[debug(format = "{name} <{flags:#06b}> {{{name}}}")]
+pub struct Entry {
+    name: &'static str,
+    flags: u8,
+    cache: Vec<u8>,
+}
+
+#[derive(CustomDebug)]
+#[debug(format = "Line from {start} to {end}")]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+fn main() {
+    assert_eq!(format!("{:?}", Point { x: 1, y: -2 }), "Point(1, -2)");
+
+    let entry = Entry { name: "a", flags: 0b101, cache: vec![1, 2, 3] };
+    assert_eq!(format!("{:?}", entry), r#""a" <0b0101> {"a"}"#);
+
+    let line = Line {
+        start: Point { x: 0, y: 0 },
+        end: Point { x: 3, y: 4 },
+    };
+    assert_eq!(format!("{:?}", line), "Line from Point(0, 0) to Point(3, 4)");
+}
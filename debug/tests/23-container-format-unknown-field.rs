@@ -0,0 +1,13 @@
+// Every placeholder in a #[debug(format = "...")] string has to name one of the
+// struct's fields.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(format = "Point({x}, {z})")]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// A field attribute #[debug("...", a, b)] formats a field with a format string
+// whose arguments are other fields of the same struct, named after the string.
+// The field itself can be among them, or left out. Without a list of fields,
+// #[debug("...")] formats just the field itself, the same as #[debug = "..."].
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Span {
+    #[debug("{}..{}", start, end)]
+    start: usize,
+    end: usize,
+    #[debug("{} bytes", len)]
+    len: usize,
+    #[debug("0x{:x}")]
+    offset: u32,
+}
+
+fn main() {
+    let span = Span {
+        start: 3,
+        end: 7,
+        len: 4,
+        offset: 255,
+    };
+
+    let debug = format!("{:?}", span);
+    let expected = "Span { start: 3..7, end: 7, len: 4 bytes, offset: 0xff }";
+
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,12 @@
+// The fields named in #[debug("...", a, b)] have to exist on the struct.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Span {
+    #[debug("{}..{}", start, stop)]
+    start: usize,
+    end: usize,
+}
+
+fn main() {}
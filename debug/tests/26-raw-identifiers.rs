@@ -0,0 +1,41 @@
+// Fields can have raw identifiers as names, such as `r#type` for a field
+// called `type`. The generated code has to keep using the raw identifier to
+// access the field, while the debug output labels it without the `r#` prefix.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Token {
+    r#type: u8,
+    #[debug = "{}!"]
+    r#match: bool,
+    #[debug("{}:{}", r#type, r#match)]
+    summary: (),
+}
+
+#[derive(CustomDebug)]
+#[debug(format = "{type}/{match}")]
+pub struct Compact {
+    r#type: u8,
+    r#match: bool,
+}
+
+fn main() {
+    let token = Token {
+        r#type: 1,
+        r#match: true,
+        summary: (),
+    };
+
+    let debug = format!("{:?}", token);
+    let expected = "Token { type: 1, match: true!, summary: 1:true }";
+
+    assert_eq!(debug, expected);
+
+    let compact = Compact {
+        r#type: 2,
+        r#match: false,
+    };
+
+    assert_eq!(format!("{:?}", compact), "2/false");
+}
@@ -0,0 +1,12 @@
+// Deriving CustomDebug on an enum is an error at the enum itself, rather than
+// a missing Debug impl wherever it is first formatted.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main() {}
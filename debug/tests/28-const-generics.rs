@@ -0,0 +1,24 @@
+// A const generic parameter appears in the impl header like any other
+// parameter, but only type parameters get a `Debug` bound. Here `T` is only
+// used as the element type of an array whose length is the const parameter.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Buffer<const N: usize, T> {
+    data: [T; N],
+    #[debug = "{} used"]
+    len: usize,
+}
+
+fn main() {
+    let buffer = Buffer {
+        data: ['a', 'b', 'c'],
+        len: 2,
+    };
+
+    let debug = format!("{:?}", buffer);
+    let expected = "Buffer { data: ['a', 'b', 'c'], len: 2 used }";
+
+    assert_eq!(debug, expected);
+}
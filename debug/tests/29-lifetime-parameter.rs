@@ -0,0 +1,32 @@
+// Lifetime parameters are kept in the impl header as they are, with no bounds
+// added to them. A type parameter behind a reference gets a plain `T: Debug`
+// bound, since `&'a T` is Debug whenever `T` is.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Ref<'a, T> {
+    r: &'a T,
+}
+
+#[derive(CustomDebug)]
+pub struct Pair<'a, 'b: 'a, T, U> {
+    first: &'a T,
+    second: &'b mut U,
+    label: &'a str,
+}
+
+fn main() {
+    let value = vec![1, 2];
+    let r = Ref { r: &value };
+    assert_eq!(format!("{:?}", r), "Ref { r: [1, 2] }");
+
+    let first = 'x';
+    let mut second = Some(3);
+    let pair = Pair {
+        first: &first,
+        second: &mut second,
+        label: "pair",
+    };
+    assert_eq!(format!("{:?}", pair), r#"Pair { first: 'x', second: Some(3), label: "pair" }"#);
+}
@@ -9,4 +9,25 @@ fn tests() {
     //t.pass("tests/06-bound-trouble.rs");  // Unimplementable test case, apparently
     t.pass("tests/07-associated-type.rs");
     t.pass("tests/08-escape-hatch.rs");
+    t.pass("tests/09-skip-field.rs");
+    t.compile_fail("tests/10-skip-with-format.rs");
+    t.pass("tests/11-format-with.rs");
+    t.pass("tests/12-existing-where-clause.rs");
+    t.pass("tests/13-bound-replaces-inferred.rs");
+    t.pass("tests/14-alternate.rs");
+    t.pass("tests/15-rename-field.rs");
+    t.compile_fail("tests/16-rename-skipped-field.rs");
+    t.pass("tests/17-transparent.rs");
+    t.compile_fail("tests/18-transparent-multiple-fields.rs");
+    t.pass("tests/19-phantom-data-paths.rs");
+    t.pass("tests/20-unit-struct.rs");
+    t.pass("tests/21-nested-associated-types.rs");
+    t.pass("tests/22-container-format.rs");
+    t.compile_fail("tests/23-container-format-unknown-field.rs");
+    t.pass("tests/24-format-sibling-fields.rs");
+    t.compile_fail("tests/25-format-unknown-sibling.rs");
+    t.pass("tests/26-raw-identifiers.rs");
+    t.compile_fail("tests/27-enum.rs");
+    t.pass("tests/28-const-generics.rs");
+    t.pass("tests/29-lifetime-parameter.rs");
 }
@@ -1,54 +1,371 @@
 use std::cmp::Ordering;
+use std::fmt::{self, Display};
 
 use proc_macro::TokenStream;
-use proc_macro2::Span;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
+    Attribute,
+    Expr,
+    ExprLit,
     ExprMatch,
     Ident,
-    Item::{self, Enum},
+    Fields,
+    Item::{self, Enum, Struct},
     ItemFn,
+    ItemStruct,
+    Lit,
+    LitStr,
     Meta,
+    MetaList,
+    MetaNameValue,
     Path,
+    PatLit,
+    PatOr,
+    parse::{Parse, ParseStream},
+    Token,
+    UseName,
+    UsePath,
+    UseRename,
+    UseTree,
+    Variant,
     visit_mut::{self, VisitMut}, Arm, parse_macro_input, parse_quote, Pat,
 };
 
+// The arguments of the #[sorted] and #[sorted::check] attributes, such as:
+// #[sorted(reverse)]
+// #[sorted(case_insensitive)]
+// #[sorted(natural)]
+#[derive(Default, Clone, Copy)]
+struct SortOptions {
+    reverse: bool,
+    case_insensitive: bool,
+    // Compares runs of digits by their numeric value, so that `V2` sorts before `V10`
+    natural: bool,
+}
+
+impl Parse for SortOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = SortOptions::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+
+            if key == "reverse" && !options.reverse {
+                options.reverse = true;
+            } else if key == "case_insensitive" && !options.case_insensitive {
+                options.case_insensitive = true;
+            } else if key == "natural" && !options.natural {
+                options.natural = true;
+            } else {
+                return Err(syn::Error::new(key.span(), "expected 'reverse', 'case_insensitive' or 'natural'"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(options)
+    }
+}
+
+impl SortOptions {
+    // Options given on a match inside a #[sorted::check] function add to those given on the
+    // function itself
+    fn merge(self, other: SortOptions) -> SortOptions {
+        SortOptions {
+            reverse: self.reverse || other.reverse,
+            case_insensitive: self.case_insensitive || other.case_insensitive,
+            natural: self.natural || other.natural,
+        }
+    }
+
+    fn compare_idents(&self, a: &Ident, b: &Ident) -> Ordering {
+        if self.case_insensitive || self.natural {
+            self.compare_strs(&a.to_string(), &b.to_string())
+        } else {
+            a.cmp(b)
+        }
+    }
+
+    // Compares identifiers and string literals
+    fn compare_strs(&self, a: &str, b: &str) -> Ordering {
+        let (a, b) =
+            if self.case_insensitive {
+                (a.to_ascii_lowercase(), b.to_ascii_lowercase())
+            } else {
+                (a.to_owned(), b.to_owned())
+            };
+
+        if self.natural {
+            compare_natural(&a, &b)
+        } else {
+            a.cmp(&b)
+        }
+    }
+
+    // Turns the natural ordering of two items into the order that they are expected to be in
+    fn order(&self, ordering: Ordering) -> Ordering {
+        if self.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+// Compares two strings piece by piece, where each piece is either a run of digits or a run of
+// anything else. Runs of digits are compared by their numeric value, and when the values are equal
+// the run with fewer leading zeros sorts first.
+fn compare_natural(a: &str, b: &str) -> Ordering {
+    let mut a_pieces = natural_pieces(a).into_iter();
+    let mut b_pieces = natural_pieces(b).into_iter();
+
+    loop {
+        match (a_pieces.next(), b_pieces.next()) {
+            (Some(a_piece), Some(b_piece)) => {
+                let ordering =
+                    if is_digits(a_piece) && is_digits(b_piece) {
+                        let a_value = a_piece.trim_start_matches('0');
+                        let b_value = b_piece.trim_start_matches('0');
+                        a_value.len().cmp(&b_value.len())
+                            .then_with(|| a_value.cmp(b_value))
+                            .then_with(|| a_piece.len().cmp(&b_piece.len()))
+                    } else {
+                        a_piece.cmp(b_piece)
+                    };
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            },
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+fn natural_pieces(s: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut piece_start = 0;
+
+    for (index, c) in s.char_indices().skip(1) {
+        let previous_is_digit = s[..index].ends_with(|previous: char| previous.is_ascii_digit());
+        if c.is_ascii_digit() != previous_is_digit {
+            pieces.push(&s[piece_start..index]);
+            piece_start = index;
+        }
+    }
+
+    if piece_start < s.len() {
+        pieces.push(&s[piece_start..]);
+    }
+
+    pieces
+}
+
+fn is_digits(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
 #[proc_macro_attribute]
-pub fn sorted(_args: TokenStream, input: TokenStream) -> TokenStream {
-    match sorted_impl(input) {
+pub fn sorted(args: TokenStream, input: TokenStream) -> TokenStream {
+    match sorted_impl(args, input) {
         Ok(output) => output,
         Err(err) => err.to_compile_error().into()
     }
 }
 
-fn sorted_impl(input: TokenStream) -> syn::Result<TokenStream> {
-    let item: Item = syn::parse(input.clone())?;
+fn sorted_impl(args: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let options: SortOptions = syn::parse(args)?;
+    let mut item: Item = syn::parse(input)?;
 
-    if let Enum(item_enum) = item {
-        let mut previous_variant_ident: Option<&Ident> = None;
+    // The item is emitted again rather than passed through, so that a repeated #[sorted] on it is
+    // checked once and never left behind in the expansion
+    match &mut item {
+        Enum(item_enum) => {
+            let sorted_variants = item_enum.variants
+                .iter()
+                .map(|variant| Ok(SortedVariant { ident: &variant.ident, sort_key: variant_sort_key(variant)? }))
+                .collect::<syn::Result<Vec<_>>>()?;
+            check_sorted(&sorted_variants, |a, b| options.compare_strs(&a.key(), &b.key()), &options)?;
 
-        for variant in &item_enum.variants {
-            if let Some(previous_variant_ident) = previous_variant_ident {
-                if variant.ident < *previous_variant_ident {
-                    let sort_before_variant = item_enum.variants.iter().find(|v| v.ident > variant.ident).unwrap();
-                    return Err(syn::Error::new_spanned(&variant.ident, format!("{} should sort before {}", variant.ident, sort_before_variant.ident)));
-                }
+            // The sort keys are only for #[sorted], and would be unknown attributes otherwise
+            for variant in &mut item_enum.variants {
+                variant.attrs.retain(|attr| !attr.path().is_ident("sort_key"));
             }
+            strip_sorted_attributes(&mut item_enum.attrs);
+            Ok(item.into_token_stream().into())
+        },
+        Struct(ItemStruct { attrs, fields: Fields::Named(fields), .. }) => {
+            let field_idents: Vec<&Ident> = fields.named.iter().filter_map(|field| field.ident.as_ref()).collect();
+            check_idents_sorted(&field_idents, &options)?;
+
+            strip_sorted_attributes(attrs);
+            Ok(item.into_token_stream().into())
+        },
+        Item::Use(item_use) => {
+            check_use_tree_sorted(&item_use.tree, &options)?;
+
+            strip_sorted_attributes(&mut item_use.attrs);
+            Ok(item.into_token_stream().into())
+        },
+        item => Err(syn::Error::new_spanned(&*item, format!("#[sorted] cannot be applied to {}", describe_item(item)))),
+    }
+}
+
+// Describes an item that #[sorted] doesn't support, for use in an error message
+fn describe_item(item: &Item) -> &'static str {
+    match item {
+        Item::Const(_) => "a constant",
+        Item::ExternCrate(_) => "an extern crate declaration",
+        Item::Fn(_) => "a function",
+        Item::ForeignMod(_) => "an extern block",
+        Item::Impl(_) => "an impl block",
+        Item::Macro(_) => "a macro invocation",
+        Item::Mod(_) => "a module",
+        Item::Static(_) => "a static",
+        Item::Struct(ItemStruct { fields: Fields::Unit, .. }) => "a unit struct",
+        Item::Struct(ItemStruct { fields: Fields::Unnamed(_), .. }) => "a tuple struct",
+        Item::Struct(_) => "a struct",
+        Item::Trait(_) => "a trait",
+        Item::TraitAlias(_) => "a trait alias",
+        Item::Type(_) => "a type alias",
+        Item::Union(_) => "a union",
+        _ => "this item",
+    }
+}
+
+// An enum variant, which is sorted by its `#[sort_key = "..."]` if it has one and by its name
+// otherwise. Protocol enums, for example, can be kept in the order of their wire values while
+// having descriptive names.
+struct SortedVariant<'a> {
+    ident: &'a Ident,
+    sort_key: Option<LitStr>,
+}
+
+impl SortedVariant<'_> {
+    fn key(&self) -> String {
+        match &self.sort_key {
+            Some(sort_key) => sort_key.value(),
+            None => self.ident.to_string(),
+        }
+    }
+}
+
+impl ToTokens for SortedVariant<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.ident.to_tokens(tokens)
+    }
+}
+
+impl Display for SortedVariant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.ident, f)
+    }
+}
+
+fn variant_sort_key(variant: &Variant) -> syn::Result<Option<LitStr>> {
+    let mut sort_key = None;
 
-            previous_variant_ident = Some(&variant.ident);
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("sort_key")) {
+        match &attr.meta {
+            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Str(key), .. }), .. }) if sort_key.is_none() => {
+                sort_key = Some(key.clone());
+            },
+            Meta::NameValue(_) if sort_key.is_some() => return Err(syn::Error::new_spanned(attr, "expected only one `sort_key` attribute")),
+            _ => return Err(syn::Error::new_spanned(attr, "expected `sort_key = \"...\"`")),
         }
+    }
+
+    Ok(sort_key)
+}
+
+// Checks the names of a struct's fields or the names in a `use` group
+fn check_idents_sorted(idents: &[&Ident], options: &SortOptions) -> syn::Result<()> {
+    check_sorted(idents, |a, b| options.compare_idents(a, b), options)
+}
 
-        Ok(input)
-    } else {
-        Err(syn::Error::new(Span::call_site(), "expected enum or match expression"))
+// Checks that items are in order by the given comparison, reporting every one that is out of order
+// rather than just the first
+fn check_sorted<T: ToTokens + Display>(items: &[T], compare: impl Fn(&T, &T) -> Ordering, options: &SortOptions) -> syn::Result<()> {
+    let mut error: Option<syn::Error> = None;
+    let mut previous_item: Option<&T> = None;
+
+    for (index, item) in items.iter().enumerate() {
+        if let Some(previous_item) = previous_item {
+            if options.order(compare(item, previous_item)) == Ordering::Less {
+                // The earliest of the items before this one that should come after it. There is
+                // always one, since the previous item does.
+                let sort_before_item = items[..index].iter().find(|other_item| options.order(compare(other_item, item)) == Ordering::Greater).unwrap();
+                let this_error = syn::Error::new_spanned(item, format!("{} should sort before {}", item, sort_before_item));
+                match &mut error {
+                    Some(error) => error.combine(this_error),
+                    None => error = Some(this_error),
+                }
+            }
+        }
+
+        previous_item = Some(item);
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+// Checks the names inside each brace group of a `use` declaration, including nested groups. A
+// `self` import and glob or unnamed group imports are left out of the ordering.
+fn check_use_tree_sorted(tree: &UseTree, options: &SortOptions) -> syn::Result<()> {
+    match tree {
+        UseTree::Path(use_path) => check_use_tree_sorted(&use_path.tree, options),
+        UseTree::Group(use_group) => {
+            let mut error: Option<syn::Error> = None;
+
+            let idents: Vec<&Ident> = use_group.items
+                .iter()
+                .filter_map(|item| {
+                    match item {
+                        UseTree::Path(UsePath { ident, .. })
+                            | UseTree::Name(UseName { ident })
+                            | UseTree::Rename(UseRename { ident, .. }) => Some(ident),
+                        UseTree::Glob(_) | UseTree::Group(_) => None,
+                    }
+                })
+                .filter(|ident| *ident != "self")
+                .collect();
+
+            let results = std::iter::once(check_idents_sorted(&idents, options))
+                .chain(use_group.items.iter().map(|item| check_use_tree_sorted(item, options)));
+
+            for result in results {
+                if let Err(this_error) = result {
+                    match &mut error {
+                        Some(error) => error.combine(this_error),
+                        None => error = Some(this_error),
+                    }
+                }
+            }
+
+            match error {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        },
+        UseTree::Name(_) | UseTree::Rename(_) | UseTree::Glob(_) => Ok(()),
     }
 }
 
 #[proc_macro_attribute]
-pub fn check(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn check(args: TokenStream, input: TokenStream) -> TokenStream {
+    let options = parse_macro_input!(args as SortOptions);
     let mut item = parse_macro_input!(input as ItemFn);
 
-    let mut check_visitor = CheckVisitor::new();
+    let mut check_visitor = CheckVisitor::new(options);
     check_visitor.visit_item_fn_mut(&mut item);
 
     let error_tokens = match check_visitor.error {
@@ -64,11 +381,12 @@ pub fn check(_args: TokenStream, input: TokenStream) -> TokenStream {
 
 struct CheckVisitor {
     error: Option<syn::Error>,
+    options: SortOptions,
 }
 
 impl CheckVisitor {
-    fn new() -> Self {
-        Self { error: None }
+    fn new(options: SortOptions) -> Self {
+        Self { error: None, options }
     }
 
     fn add_error(&mut self, error: syn::Error) {
@@ -77,31 +395,63 @@ impl CheckVisitor {
             None => self.error = Some(error),
         }
     }
+
+    // The alternatives of an or-pattern have to be sorted among themselves, too
+    fn check_or_pattern_sorted(&mut self, pat_or: &PatOr, options: &SortOptions) {
+        let keys: Vec<SortKey> = pat_or.cases.iter().filter_map(sort_key_from_pat).collect();
+
+        for (index, key) in keys.iter().enumerate().skip(1) {
+            if compare_sort_keys(key, &keys[index - 1], options) == Ordering::Less {
+                let sort_before_key = keys.iter().find(|other_key| compare_sort_keys(other_key, key, options) == Ordering::Greater).unwrap();
+                self.add_error(syn::Error::new_spanned(key, format!("{} should sort before {} in this pattern", key, sort_before_key)));
+            }
+        }
+    }
 }
 
 impl VisitMut for CheckVisitor {
-    fn visit_expr_match_mut(self: &mut Self, expr_match: &mut ExprMatch) {
-        let mut previous_arm_path: Option<Path> = None;
-        let mut wildcard_pat: Option<&Pat> = None;
+    fn visit_expr_match_mut(&mut self, expr_match: &mut ExprMatch) {
+        let mut options = self.options;
+        let mut is_sorted = false;
 
-        for arm in &expr_match.arms {
-            if let Some(wildcard_pat) = &wildcard_pat {
-                self.add_error(syn::Error::new_spanned(wildcard_pat, "wildcard pattern should be last"));
+        for attr in &expr_match.attrs {
+            match &attr.meta {
+                Meta::Path(path) if path.is_ident("sorted") => is_sorted = true,
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident("sorted") => {
+                    is_sorted = true;
+                    match syn::parse2::<SortOptions>(tokens.clone()) {
+                        Ok(match_options) => options = options.merge(match_options),
+                        Err(error) => self.add_error(error),
+                    }
+                },
+                _ => {},
             }
+        }
+
+        // Only the matches marked #[sorted] are checked, but any of them may be nested inside one
+        // that isn't
+        if !is_sorted {
+            visit_mut::visit_expr_match_mut(self, expr_match);
+            return;
+        }
 
-            if let Some(path) = path_from_match_arm(arm) {
-                if let Some(previous_arm_path) = previous_arm_path {
-                    if compare_paths(&path, &previous_arm_path) == Ordering::Less {
-                        let sort_before_arm_path: Path = expr_match.arms
+        let mut previous_arm_key: Option<SortKey> = None;
+        let mut first_wildcard: Option<(usize, &Pat)> = None;
+
+        for (arm_index, arm) in expr_match.arms.iter().enumerate() {
+            if let Some(key) = sort_key_from_match_arm(arm) {
+                if let Pat::Or(pat_or) = &arm.pat {
+                    self.check_or_pattern_sorted(pat_or, &options);
+                }
+
+                if let Some(previous_arm_key) = previous_arm_key {
+                    if compare_sort_keys(&key, &previous_arm_key, &options) == Ordering::Less {
+                        let sort_before_arm_key: SortKey = expr_match.arms
                             .iter()
-                            .map(path_from_match_arm)
-                            .find(|possible_sort_before_path| {
-                                if let Some(possible_sort_before_path) = possible_sort_before_path {
-                                    if compare_paths(possible_sort_before_path, &path) == Ordering::Greater {
-                                        true
-                                    } else {
-                                        false
-                                    }
+                            .map(sort_key_from_match_arm)
+                            .find(|possible_sort_before_key| {
+                                if let Some(possible_sort_before_key) = possible_sort_before_key {
+                                    compare_sort_keys(possible_sort_before_key, &key, &options) == Ordering::Greater
                                 } else {
                                     false
                                 }
@@ -109,37 +459,96 @@ impl VisitMut for CheckVisitor {
                             .unwrap()
                             .unwrap();
 
-                        self.add_error(syn::Error::new_spanned(&path, format!("{} should sort before {}", path_to_string(&path), path_to_string(&sort_before_arm_path))));
+                        self.add_error(syn::Error::new_spanned(&key, format!("{} should sort before {}", key, sort_before_arm_key)));
                     }
                 }
 
-                previous_arm_path = Some(path);
+                previous_arm_key = Some(key);
             } else if let Pat::Wild(_) = &arm.pat {
-                wildcard_pat = Some(&arm.pat);
+                if first_wildcard.is_none() {
+                    first_wildcard = Some((arm_index, &arm.pat));
+                }
             } else {
                 self.add_error(syn::Error::new_spanned(&arm.pat, "unsupported by #[sorted]"));
             }
         }
 
-        // Remove the #[sorted] attribute (which would otherwise cause a compile error)
-        expr_match.attrs.retain(|attr| {
-            if let Meta::Path(path) = &attr.meta {
-                if path.is_ident("sorted") {
-                    false
-                } else {
-                    true
-                }
-            } else {
-                true
+        // A misplaced wildcard is reported once, however many arms follow it
+        if let Some((wildcard_index, wildcard_pat)) = first_wildcard {
+            let following_arm_count = expr_match.arms.len() - wildcard_index - 1;
+            if following_arm_count > 0 {
+                let message = match following_arm_count {
+                    1 => "wildcard pattern should be last, but 1 arm follows it".to_owned(),
+                    _ => format!("wildcard pattern should be last, but {} arms follow it", following_arm_count),
+                };
+                self.add_error(syn::Error::new_spanned(wildcard_pat, message));
             }
-        });
+        }
+
+        // Remove the #[sorted] attribute (which would otherwise cause a compile error)
+        strip_sorted_attributes(&mut expr_match.attrs);
 
         visit_mut::visit_expr_match_mut(self, expr_match)
     }
 }
 
-fn path_from_match_arm(arm: &Arm) -> Option<Path> {
+// Removes every #[sorted] attribute, with or without options
+fn strip_sorted_attributes(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| {
+        match &attr.meta {
+            Meta::Path(path) | Meta::List(MetaList { path, .. }) => !path.is_ident("sorted"),
+            _ => true,
+        }
+    });
+}
+
+// What a match arm is sorted by: the path that its pattern matches, or a literal value
+enum SortKey {
+    Path(Path),
+    Lit(Lit),
+}
+
+impl ToTokens for SortKey {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            SortKey::Path(path) => path.to_tokens(tokens),
+            SortKey::Lit(lit) => lit.to_tokens(tokens),
+        }
+    }
+}
+
+impl Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortKey::Path(path) => f.write_str(&path_to_string(path)),
+            SortKey::Lit(lit) => write!(f, "{}", lit.to_token_stream()),
+        }
+    }
+}
+
+// An or-pattern such as `A | B` is sorted by its first alternative, as long as every alternative
+// is supported. Only the pattern matters, so an arm with an `if` guard sorts the same as one
+// without, and several arms may share a pattern with different guards.
+fn sort_key_from_match_arm(arm: &Arm) -> Option<SortKey> {
     match &arm.pat {
+        Pat::Or(pat_or) => {
+            let mut keys = pat_or.cases.iter().map(sort_key_from_pat);
+            let first_key = keys.next()??;
+            keys.all(|key| key.is_some()).then_some(first_key)
+        },
+        pat => sort_key_from_pat(pat),
+    }
+}
+
+fn sort_key_from_pat(pat: &Pat) -> Option<SortKey> {
+    match pat {
+        Pat::Lit(PatLit { lit: lit @ (Lit::Int(_) | Lit::Char(_) | Lit::Str(_)), .. }) => Some(SortKey::Lit(lit.clone())),
+        _ => path_from_pat(pat).map(SortKey::Path),
+    }
+}
+
+fn path_from_pat(pat: &Pat) -> Option<Path> {
+    match pat {
         Pat::Ident(ident) => {
             let path: Path = parse_quote!(#ident);
             Some(path)
@@ -151,7 +560,27 @@ fn path_from_match_arm(arm: &Arm) -> Option<Path> {
     }
 }
 
-fn compare_paths(a: &Path, b: &Path) -> Ordering {
+// Literals are compared by value, so that integers are in numeric order and strings are compared
+// without their quotes and escapes. The arms of a single match all have the same kind of key, so
+// other combinations never need to be ordered.
+fn compare_sort_keys(a: &SortKey, b: &SortKey, options: &SortOptions) -> Ordering {
+    let ordering = match (a, b) {
+        (SortKey::Path(a), SortKey::Path(b)) => compare_paths(a, b, options),
+        (SortKey::Lit(Lit::Int(a)), SortKey::Lit(Lit::Int(b))) => {
+            match (a.base10_parse::<i128>(), b.base10_parse::<i128>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                _ => Ordering::Equal,
+            }
+        },
+        (SortKey::Lit(Lit::Char(a)), SortKey::Lit(Lit::Char(b))) => a.value().cmp(&b.value()),
+        (SortKey::Lit(Lit::Str(a)), SortKey::Lit(Lit::Str(b))) => options.compare_strs(&a.value(), &b.value()),
+        _ => Ordering::Equal,
+    };
+
+    options.order(ordering)
+}
+
+fn compare_paths(a: &Path, b: &Path, options: &SortOptions) -> Ordering {
     let mut a_iter = a.segments.iter();
     let mut b_iter = b.segments.iter();
 
@@ -161,7 +590,7 @@ fn compare_paths(a: &Path, b: &Path) -> Ordering {
 
         match (a_segment, b_segment) {
             (Some(a), Some(b)) => {
-                match a.ident.cmp(&b.ident) {
+                match options.compare_idents(&a.ident, &b.ident) {
                     Ordering::Greater => return Ordering::Greater,
                     Ordering::Less => return Ordering::Less,
                     _ => ()
@@ -177,7 +606,7 @@ fn compare_paths(a: &Path, b: &Path) -> Ordering {
 fn path_to_string(path: &Path) -> String {
     let mut output = String::new();
 
-    if let Some(_) = path.leading_colon {
+    if path.leading_colon.is_some() {
         output.push_str("::");
     }
 
@@ -0,0 +1,25 @@
+// The #[sorted] macro can also be applied to a struct with named fields, to
+// keep the fields in alphabetical order. The struct is left unchanged.
+
+use sorted::sorted;
+
+#[sorted]
+pub struct Config {
+    pub cache_dir: String,
+    pub jobs: usize,
+    pub offline: bool,
+    pub target: Option<String>,
+    pub verbose: bool,
+}
+
+fn main() {
+    let config = Config {
+        cache_dir: "/tmp".to_owned(),
+        jobs: 4,
+        offline: false,
+        target: None,
+        verbose: true,
+    };
+
+    assert_eq!(config.jobs, 4);
+}
@@ -0,0 +1,14 @@
+// An out-of-order field in a #[sorted] struct is reported the same way as an
+// out-of-order enum variant.
+
+use sorted::sorted;
+
+#[sorted]
+pub struct Config {
+    pub cache_dir: String,
+    pub offline: bool,
+    pub jobs: usize,
+    pub verbose: bool,
+}
+
+fn main() {}
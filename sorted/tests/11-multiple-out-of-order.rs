@@ -0,0 +1,16 @@
+// When several variants are out of order, each of them is reported, so that
+// they can all be fixed at once.
+
+use sorted::sorted;
+
+#[sorted]
+pub enum Error {
+    Fmt,
+    Eof,
+    Io,
+    Syntax,
+    Parse,
+    Utf8,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Match arms whose patterns are integer or character literals are sorted by
+// their value, so integers are in numeric order rather than the order of their
+// digits. A wildcard arm still has to come last.
+
+#[sorted::check]
+fn describe(n: i32, c: char) -> &'static str {
+    #[sorted]
+    match n {
+        -1 => "negative",
+        0 => "zero",
+        2 => "two",
+        10 => "ten",
+        0x20 => "thirty-two",
+        _ => "other",
+    };
+
+    #[sorted]
+    match c {
+        'a' => "a",
+        'b' => "b",
+        'z' => "z",
+        _ => "other",
+    }
+}
+
+fn main() {
+    assert_eq!(describe(10, 'b'), "b");
+}
@@ -0,0 +1,14 @@
+// Integer literal arms that are out of numeric order are reported.
+
+#[sorted::check]
+fn describe(n: u32) -> &'static str {
+    #[sorted]
+    match n {
+        1 => "one",
+        3 => "three",
+        2 => "two",
+        _ => "other",
+    }
+}
+
+fn main() {}
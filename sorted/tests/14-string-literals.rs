@@ -0,0 +1,19 @@
+// Match arms whose patterns are string literals are sorted by the value of the
+// string. A wildcard arm still has to come last.
+
+#[sorted::check]
+fn status_code(status: &str) -> u16 {
+    #[sorted]
+    match status {
+        "created" => 201,
+        "not found" => 404,
+        "ok" => 200,
+        "teapot" => 418,
+        _ => 500,
+    }
+}
+
+fn main() {
+    assert_eq!(status_code("ok"), 200);
+    assert_eq!(status_code("gone"), 500);
+}
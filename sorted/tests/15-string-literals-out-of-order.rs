@@ -0,0 +1,15 @@
+// String literal arms that are out of order are reported using the literal as
+// it was written.
+
+#[sorted::check]
+fn status_code(status: &str) -> u16 {
+    #[sorted]
+    match status {
+        "a" => 1,
+        "c" => 3,
+        "b" => 2,
+        _ => 0,
+    }
+}
+
+fn main() {}
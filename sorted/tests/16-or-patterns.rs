@@ -0,0 +1,37 @@
+// A match arm with an or-pattern such as `A | B` is sorted by its first
+// alternative, and its alternatives have to be in order among themselves.
+
+#[derive(Clone, Copy)]
+pub enum Token {
+    Comma,
+    Minus,
+    Number(u32),
+    Plus,
+    Semicolon,
+    Slash,
+    Star,
+}
+
+#[sorted::check]
+fn describe(token: Token) -> &'static str {
+    #[sorted]
+    match token {
+        Token::Comma | Token::Semicolon => "separator",
+        Token::Minus | Token::Plus | Token::Slash | Token::Star => "operator",
+        Token::Number(_) => "number",
+    }
+}
+
+#[sorted::check]
+fn is_vowel(c: char) -> bool {
+    #[sorted]
+    match c {
+        'a' | 'e' | 'i' | 'o' | 'u' => true,
+        _ => false,
+    }
+}
+
+fn main() {
+    assert_eq!(describe(Token::Plus), "operator");
+    assert!(is_vowel('o'));
+}
@@ -0,0 +1,33 @@
+// Or-patterns are checked in two ways: each arm is ordered by its first
+// alternative among the other arms, and the alternatives within an arm have to
+// be ordered too.
+
+pub enum Token {
+    Comma,
+    Minus,
+    Number(u32),
+    Plus,
+    Semicolon,
+}
+
+#[sorted::check]
+fn describe(token: Token) -> &'static str {
+    #[sorted]
+    match token {
+        Token::Minus | Token::Plus => "operator",
+        Token::Comma => "comma",
+        Token::Number(_) => "number",
+        Token::Semicolon => "semicolon",
+    }
+}
+
+#[sorted::check]
+fn is_separator(token: Token) -> bool {
+    #[sorted]
+    match token {
+        Token::Semicolon | Token::Comma => true,
+        _ => false,
+    }
+}
+
+fn main() {}
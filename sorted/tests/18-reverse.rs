@@ -0,0 +1,38 @@
+// With #[sorted(reverse)], variants are expected in descending order instead.
+// The same argument can be given to #[sorted::check], where it applies to
+// every match in the function, or to the #[sorted] attribute of a single
+// match.
+
+use sorted::sorted;
+
+#[sorted(reverse)]
+pub enum Priority {
+    Urgent,
+    Normal,
+    Low,
+}
+
+#[sorted::check(reverse)]
+fn weight(priority: &Priority) -> u32 {
+    #[sorted]
+    match priority {
+        Priority::Urgent => 100,
+        Priority::Normal => 10,
+        Priority::Low => 1,
+    }
+}
+
+#[sorted::check]
+fn threshold(level: u32) -> &'static str {
+    #[sorted(reverse)]
+    match level {
+        100 => "urgent",
+        10 => "normal",
+        _ => "low",
+    }
+}
+
+fn main() {
+    assert_eq!(weight(&Priority::Normal), 10);
+    assert_eq!(threshold(100), "urgent");
+}
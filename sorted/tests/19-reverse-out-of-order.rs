@@ -0,0 +1,12 @@
+// An enum in ascending order is out of order under #[sorted(reverse)].
+
+use sorted::sorted;
+
+#[sorted(reverse)]
+pub enum Priority {
+    Low,
+    Normal,
+    Urgent,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// By default identifiers are compared byte by byte, so every uppercase letter
+// sorts before every lowercase one and `HTTPS` comes before `Http`. With
+// #[sorted(case_insensitive)], identifiers and string literals are compared
+// ignoring ASCII case instead.
+
+use sorted::sorted;
+
+#[sorted(case_insensitive)]
+pub enum Scheme {
+    Ftp,
+    Http,
+    HTTPS,
+    Https,
+    SSH,
+}
+
+#[sorted::check(case_insensitive)]
+fn default_port(scheme: &Scheme) -> u16 {
+    #[sorted]
+    match scheme {
+        Scheme::Ftp => 21,
+        Scheme::Http => 80,
+        Scheme::HTTPS | Scheme::Https => 443,
+        Scheme::SSH => 22,
+    }
+}
+
+#[sorted::check]
+fn parse(name: &str) -> Option<Scheme> {
+    #[sorted(case_insensitive)]
+    match name {
+        "ftp" => Some(Scheme::Ftp),
+        "HTTP" => Some(Scheme::Http),
+        "https" => Some(Scheme::Https),
+        "SSH" => Some(Scheme::SSH),
+        _ => None,
+    }
+}
+
+fn main() {
+    assert_eq!(default_port(&Scheme::HTTPS), 443);
+    assert!(parse("ftp").is_some());
+}
@@ -0,0 +1,12 @@
+// Without the case_insensitive option, `HTTPS` has to come before `Http`.
+
+use sorted::sorted;
+
+#[sorted]
+pub enum Scheme {
+    Ftp,
+    Http,
+    HTTPS,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// Match arms with `if` guards are sorted by their patterns, ignoring the
+// guards. Consecutive arms can have the same pattern with different guards.
+
+#[derive(Clone, Copy)]
+pub enum Shape {
+    Circle(f64),
+    Rectangle(f64, f64),
+    Triangle(f64, f64, f64),
+}
+
+#[sorted::check]
+fn describe(shape: Shape) -> &'static str {
+    #[sorted]
+    match shape {
+        Shape::Circle(r) if r == 0.0 => "point",
+        Shape::Circle(_) => "circle",
+        Shape::Rectangle(w, h) if w == h => "square",
+        Shape::Rectangle(..) => "rectangle",
+        Shape::Triangle(a, b, c) if a == b && b == c => "equilateral triangle",
+        Shape::Triangle(..) => "triangle",
+    }
+}
+
+fn main() {
+    assert_eq!(describe(Shape::Rectangle(2.0, 2.0)), "square");
+    assert_eq!(describe(Shape::Circle(1.0)), "circle");
+}
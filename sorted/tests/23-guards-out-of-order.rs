@@ -0,0 +1,21 @@
+// A guard does not exempt an arm from the ordering check.
+
+pub enum Shape {
+    Circle(f64),
+    Rectangle(f64, f64),
+    Triangle(f64, f64, f64),
+}
+
+#[sorted::check]
+fn describe(shape: Shape) -> &'static str {
+    #[sorted]
+    match shape {
+        Shape::Circle(_) => "circle",
+        Shape::Triangle(a, b, c) if a == b && b == c => "equilateral triangle",
+        Shape::Rectangle(w, h) if w == h => "square",
+        Shape::Rectangle(..) => "rectangle",
+        Shape::Triangle(..) => "triangle",
+    }
+}
+
+fn main() {}
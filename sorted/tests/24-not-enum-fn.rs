@@ -0,0 +1,11 @@
+// The error for an item that #[sorted] doesn't support points at the item and
+// says what kind of item it is.
+
+use sorted::sorted;
+
+#[sorted]
+pub fn variants() -> Vec<&'static str> {
+    vec!["b", "a"]
+}
+
+fn main() {}
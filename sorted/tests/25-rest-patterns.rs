@@ -0,0 +1,27 @@
+// Tuple struct and struct patterns are sorted by their paths, whatever
+// subpatterns they contain, including `..` rest patterns and ignored `_`
+// bindings.
+
+pub enum Event {
+    Click { x: i32, y: i32, button: u8 },
+    Key(char, bool),
+    Resize(u32, u32),
+    Scroll { delta: i32, horizontal: bool },
+}
+
+#[sorted::check]
+fn describe(event: &Event) -> String {
+    #[sorted]
+    match event {
+        Event::Click { button: 0, .. } => "left click".to_owned(),
+        Event::Click { .. } => "click".to_owned(),
+        Event::Key(c, ..) => format!("key {}", c),
+        Event::Resize(_, _) => "resize".to_owned(),
+        Event::Scroll { delta, .. } => format!("scroll {}", delta),
+    }
+}
+
+fn main() {
+    assert_eq!(describe(&Event::Key('a', true)), "key a");
+    assert_eq!(describe(&Event::Click { x: 0, y: 0, button: 0 }), "left click");
+}
@@ -0,0 +1,19 @@
+// Arms with rest patterns are still checked for order by their paths.
+
+pub enum Event {
+    Click { x: i32, y: i32 },
+    Key(char, bool),
+    Resize(u32, u32),
+}
+
+#[sorted::check]
+fn describe(event: &Event) -> &'static str {
+    #[sorted]
+    match event {
+        Event::Key(_, ..) => "key",
+        Event::Click { .. } => "click",
+        Event::Resize(..) => "resize",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// Plain string comparison puts `V10` before `V2`, because it compares the
+// digits one at a time. With #[sorted(natural)], runs of digits are compared
+// by their numeric value instead, as a person would order them.
+
+use sorted::sorted;
+
+#[sorted(natural)]
+pub enum Version {
+    V1,
+    V2,
+    V9,
+    V10,
+    V10a,
+    V11,
+    V100,
+}
+
+#[sorted::check(natural)]
+fn release_year(version: &Version) -> u32 {
+    #[sorted]
+    match version {
+        Version::V1 => 2015,
+        Version::V2 | Version::V9 => 2016,
+        Version::V10 | Version::V10a => 2018,
+        Version::V11 => 2021,
+        Version::V100 => 2100,
+    }
+}
+
+#[sorted::check]
+fn parse(name: &str) -> Option<Version> {
+    #[sorted(natural)]
+    match name {
+        "v1" => Some(Version::V1),
+        "v2" => Some(Version::V2),
+        "v10" => Some(Version::V10),
+        _ => None,
+    }
+}
+
+fn main() {
+    assert_eq!(release_year(&Version::V10), 2018);
+    assert!(parse("v2").is_some());
+}
@@ -0,0 +1,13 @@
+// Under #[sorted(natural)], the order that plain string comparison expects is
+// reported as out of order.
+
+use sorted::sorted;
+
+#[sorted(natural)]
+pub enum Version {
+    V1,
+    V10,
+    V2,
+}
+
+fn main() {}
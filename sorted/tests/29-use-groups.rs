@@ -0,0 +1,24 @@
+// The #[sorted] macro can be applied to a `use` declaration, to check that the
+// names inside each brace group are in order. Nested groups are checked too.
+// A `self` import may come first, and glob imports are not ordered. As with
+// enums, uppercase letters sort before lowercase ones unless the
+// case_insensitive option is given.
+
+#![allow(unused_imports)]
+
+use sorted::sorted;
+
+#[sorted]
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[sorted]
+use std::{
+    self,
+    fmt::{self, Debug, Display},
+    io::{BufRead, Read as _, Write, prelude::*},
+    sync::{Arc, Mutex, atomic::{AtomicBool, AtomicUsize}},
+};
+
+fn main() {
+    let _: HashMap<u8, u8> = HashMap::new();
+}
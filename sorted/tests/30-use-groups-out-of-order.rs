@@ -0,0 +1,13 @@
+// Out-of-order names are reported in every group of a `use` declaration,
+// whether it is the outermost group or nested inside another.
+
+use sorted::sorted;
+
+#[sorted]
+use std::{
+    collections::{HashMap, BTreeMap},
+    fmt::{Display, Debug},
+    cell::Cell,
+};
+
+fn main() {}
@@ -0,0 +1,24 @@
+// A wildcard arm that is followed by other arms is reported exactly once, at
+// the wildcard, saying how many arms follow it. The arms around it are still
+// checked for order.
+
+pub enum Color {
+    Blue,
+    Green,
+    Red,
+    Yellow,
+}
+
+#[sorted::check]
+fn describe(color: Color) -> &'static str {
+    #[sorted]
+    match color {
+        Color::Green => "green",
+        _ => "other",
+        Color::Blue => "blue",
+        Color::Red => "red",
+        Color::Yellow => "yellow",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Generic parameters and explicit discriminants don't affect the check: only
+// the variant names are compared, and each misplaced variant is reported
+// against the earliest variant before it that should come after it.
+
+use sorted::sorted;
+
+#[sorted]
+pub enum Tree<K, V> {
+    Branch(Box<Tree<K, V>>, Box<Tree<K, V>>),
+    Leaf(K, V),
+    Empty,
+    Full,
+}
+
+#[sorted]
+pub enum Status {
+    Active = 3,
+    Paused = 1,
+    Idle = 2,
+    Stopped = 0,
+}
+
+fn main() {}
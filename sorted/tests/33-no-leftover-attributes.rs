@@ -0,0 +1,58 @@
+// No #[sorted] attribute is left behind in the expansion, whichever way it is
+// reached. A leftover one on an item would run the check a second time, and
+// one on an expression would be rejected by the compiler. Here that covers a
+// repeated attribute on an item, and matches nested in closures, blocks and
+// inner functions of a #[sorted::check] function.
+
+#![deny(unused_attributes)]
+
+use sorted::sorted;
+
+#[sorted]
+#[sorted]
+pub enum Color {
+    Blue,
+    Green,
+    Red,
+}
+
+#[sorted]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[sorted::check]
+fn describe(color: Color, point: Point) -> String {
+    let brightness = |color: &Color| {
+        #[sorted]
+        match color {
+            Color::Blue => 1,
+            Color::Green => 3,
+            Color::Red => 2,
+        }
+    };
+
+    fn quadrant(point: &Point) -> &'static str {
+        #[sorted]
+        match point.x {
+            0 => "on axis",
+            _ => "off axis",
+        }
+    }
+
+    let name = {
+        #[sorted]
+        match color {
+            Color::Blue => "blue",
+            Color::Green => "green",
+            Color::Red => "red",
+        }
+    };
+
+    format!("{} {} {} {}", name, brightness(&color), quadrant(&point), point.x + point.y)
+}
+
+fn main() {
+    assert_eq!(describe(Color::Green, Point { x: 1, y: 2 }), "green 3 off axis 3");
+}
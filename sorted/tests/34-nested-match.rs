@@ -0,0 +1,37 @@
+// A #[sorted] match can be nested inside an arm of another match. Only the
+// inner one is annotated, so only its arms have to be sorted, and its
+// attribute is removed all the same. The outer match has arms in any order,
+// including patterns that #[sorted] doesn't support.
+
+#[derive(Clone, Copy)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+#[sorted::check]
+fn describe(suit: Suit, rank: u8) -> String {
+    match (rank, suit) {
+        (11..=13, _) => "face".to_owned(),
+        (1, _) => "ace".to_owned(),
+        (_, suit) => {
+            let color = #[sorted]
+            match suit {
+                Suit::Clubs => "black",
+                Suit::Diamonds => "red",
+                Suit::Hearts => "red",
+                Suit::Spades => "black",
+            };
+            format!("{} {}", color, rank)
+        },
+    }
+}
+
+fn main() {
+    assert_eq!(describe(Suit::Hearts, 12), "face");
+    assert_eq!(describe(Suit::Spades, 1), "ace");
+    assert_eq!(describe(Suit::Diamonds, 7), "red 7");
+    assert_eq!(describe(Suit::Clubs, 2), "black 2");
+}
@@ -0,0 +1,27 @@
+// The #[sorted] match nested inside an arm of another match is still checked,
+// even though the outer match isn't.
+
+#[derive(Clone, Copy)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+#[sorted::check]
+fn out_of_order(suit: Suit) -> u8 {
+    match suit {
+        Suit::Spades | Suit::Clubs => {
+            #[sorted]
+            match suit {
+                Suit::Spades => 1,
+                Suit::Clubs => 2,
+                _ => 3,
+            }
+        },
+        _ => 0,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// A variant with a #[sort_key = "..."] attribute is sorted by that key instead
+// of its name, so an enum can follow an order other than the alphabetical
+// order of its names. These variants are in the order of their wire values,
+// which wouldn't pass as names alone. The keys are compared as strings.
+
+use sorted::sorted;
+
+#[sorted]
+pub enum Message {
+    #[sort_key = "00"]
+    Hello,
+    #[sort_key = "01"]
+    Data,
+    #[sort_key = "02"]
+    Ack,
+    #[sort_key = "10"]
+    Goodbye,
+}
+
+fn main() {
+    let _ = [Message::Hello, Message::Data, Message::Ack, Message::Goodbye];
+}
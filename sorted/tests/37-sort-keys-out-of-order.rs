@@ -0,0 +1,18 @@
+// Variants with sort keys are checked against those keys. The error still
+// names the variants, since that is what appears in the code.
+
+use sorted::sorted;
+
+#[sorted]
+pub enum Message {
+    #[sort_key = "00"]
+    Hello,
+    #[sort_key = "02"]
+    Ack,
+    #[sort_key = "01"]
+    Data,
+    #[sort_key = "10"]
+    Goodbye,
+}
+
+fn main() {}
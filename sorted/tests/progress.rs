@@ -9,4 +9,33 @@ fn tests() {
     t.compile_fail("tests/06-pattern-path.rs");
     t.compile_fail("tests/07-unrecognized-pattern.rs");
     t.pass("tests/08-underscore.rs");
+    t.pass("tests/09-struct-fields.rs");
+    t.compile_fail("tests/10-struct-fields-out-of-order.rs");
+    t.compile_fail("tests/11-multiple-out-of-order.rs");
+    t.pass("tests/12-integer-literals.rs");
+    t.compile_fail("tests/13-integer-literals-out-of-order.rs");
+    t.pass("tests/14-string-literals.rs");
+    t.compile_fail("tests/15-string-literals-out-of-order.rs");
+    t.pass("tests/16-or-patterns.rs");
+    t.compile_fail("tests/17-or-patterns-out-of-order.rs");
+    t.pass("tests/18-reverse.rs");
+    t.compile_fail("tests/19-reverse-out-of-order.rs");
+    t.pass("tests/20-case-insensitive.rs");
+    t.compile_fail("tests/21-case-sensitive-by-default.rs");
+    t.pass("tests/22-guards.rs");
+    t.compile_fail("tests/23-guards-out-of-order.rs");
+    t.compile_fail("tests/24-not-enum-fn.rs");
+    t.pass("tests/25-rest-patterns.rs");
+    t.compile_fail("tests/26-rest-patterns-out-of-order.rs");
+    t.pass("tests/27-natural.rs");
+    t.compile_fail("tests/28-natural-out-of-order.rs");
+    t.pass("tests/29-use-groups.rs");
+    t.compile_fail("tests/30-use-groups-out-of-order.rs");
+    t.compile_fail("tests/31-wildcard-not-last.rs");
+    t.compile_fail("tests/32-generic-and-discriminant-enums.rs");
+    t.pass("tests/33-no-leftover-attributes.rs");
+    t.pass("tests/34-nested-match.rs");
+    t.compile_fail("tests/35-nested-match-out-of-order.rs");
+    t.pass("tests/36-sort-keys.rs");
+    t.compile_fail("tests/37-sort-keys-out-of-order.rs");
 }